@@ -2,7 +2,6 @@ extern crate annis;
 use annis::nonblocking::Client;
 use annis::{Characters::*, Error, Value};
 use std::env;
-use tokio;
 
 #[tokio::main]
 async fn main() -> Result<(), Error> {
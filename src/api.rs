@@ -0,0 +1,238 @@
+use crate::cache::Lookup;
+use crate::transport::{HttpRequest, HttpResponse};
+use crate::{
+    join_url, json_body, AuthMethod, Encoding, Endpoint, Error, ErrorKind, Page, Paginate,
+    RateLimiter, ResponseCache, RetryPolicy, Service, TokenStore,
+};
+use reqwest::header::{HeaderMap, HeaderValue, AUTHORIZATION, CONTENT_TYPE};
+use reqwest::{StatusCode, Url};
+use serde::Serialize;
+use std::sync::Arc;
+use std::time::Duration;
+
+/// What `Client` and `nonblocking::Client` have in common,
+/// to write code which works with either of them.
+/// `Output<T>` is `Result<T, Error>` for `Client`, and a future of it for `nonblocking::Client`.
+///
+/// Examples
+/// ========
+/// ```rust
+/// # use annis::{Api, Works};
+/// # use annis::models::WorksResponse;
+/// fn lain<A: Api>(api: &A) -> A::Output<Vec<String>> {
+///     let works = api.fetch(annis::works().params(vec![(Works::filter_title, "lain")]));
+///     A::map(works, |res: WorksResponse| res.works.into_iter().map(|w| w.title).collect())
+/// }
+///
+/// # #[cfg(feature = "blocking")]
+/// # fn run() -> Result<(), annis::Error> {
+/// let titles = lain(&annis::Client::set_token("access_token"))?;
+/// # Ok(())
+/// # }
+/// # #[cfg(feature = "async")]
+/// # async fn run_async() -> Result<(), annis::Error> {
+/// let titles = lain(&annis::nonblocking::Client::set_token("access_token")).await?;
+/// # Ok(())
+/// # }
+/// ```
+
+pub trait Api {
+    type Output<T: Send + 'static>;
+
+    /// Make a request with Service and decode the response to the type tied to its parameter.
+    fn fetch<K>(&self, service: Service<K>) -> Self::Output<K::Response>
+    where
+        K: Serialize + Into<String> + PartialEq + Endpoint + Send + 'static,
+        K::Response: Send + 'static;
+
+    /// Collect every item of a list endpoint, following `next_page`.
+    fn fetch_all<K>(&self, service: Service<K>) -> Self::Output<Vec<<K::Response as Page>::Item>>
+    where
+        K: Serialize + Into<String> + PartialEq + Paginate + Clone + Send + 'static,
+        K::Response: Page + Send + 'static,
+        <K::Response as Page>::Item: Send + 'static;
+
+    /// Transform the value of a successful output.
+    fn map<T, U, F>(output: Self::Output<T>, f: F) -> Self::Output<U>
+    where
+        T: Send + 'static,
+        U: Send + 'static,
+        F: FnOnce(T) -> U + Send + 'static;
+}
+
+/// The settings of a client, and the logic of its requests which doesn't depend on how they are sent.
+
+#[derive(Debug, Clone)]
+pub(crate) struct Core {
+    pub(crate) base_url: String,
+    pub(crate) auth_method: AuthMethod,
+    pub(crate) retry: RetryPolicy,
+    pub(crate) rate_limit: Option<RateLimiter>,
+    pub(crate) cache: Option<ResponseCache>,
+    pub(crate) store: Option<Arc<dyn TokenStore>>,
+}
+
+/// What a client does next for a Call.
+pub(crate) enum Step {
+    Send(HttpRequest),
+    Sleep(Duration),
+    Done(Result<HttpResponse, Error>),
+}
+
+/// A request in progress, through the cache, the rate limiter and retries.
+/// Clients drive it by doing each Step and passing the result of `Send` to the next `step()`.
+pub(crate) struct Call<'a> {
+    core: &'a Core,
+    request: HttpRequest,
+    attempt: u32,
+    waited: bool,
+    lookup: Lookup,
+}
+
+impl Core {
+    pub(crate) fn call<K>(&self, token: &str, service: Service<K>) -> Result<Call<'_>, Error>
+    where
+        K: Serialize + Into<String> + PartialEq,
+    {
+        let mut request = self.request(token, service)?;
+        let lookup = match &self.cache {
+            Some(cache) => cache.lookup(&request.method, &request.url, token, &mut request.headers),
+            None => Lookup::Skip,
+        };
+        Ok(Call {
+            core: self,
+            request,
+            attempt: 1,
+            waited: false,
+            lookup,
+        })
+    }
+
+    fn request<K>(&self, token: &str, service: Service<K>) -> Result<HttpRequest, Error>
+    where
+        K: Serialize + Into<String> + PartialEq,
+    {
+        let invalid = |e: &dyn std::fmt::Display| ErrorKind::InvalidParameter(e.to_string());
+        let mut url =
+            Url::parse(&join_url(&self.base_url, &service.path)).map_err(|e| invalid(&e))?;
+        let mut headers = HeaderMap::new();
+        match self.auth_method {
+            AuthMethod::Bearer if token.is_empty() => {}
+            AuthMethod::Bearer => {
+                let mut value = HeaderValue::from_str(&format!("Bearer {}", token))
+                    .map_err(|_| ErrorKind::InvalidParameter("access token".into()))?;
+                value.set_sensitive(true);
+                headers.insert(AUTHORIZATION, value);
+            }
+            AuthMethod::QueryParameter => {
+                url.query_pairs_mut().append_pair("access_token", token);
+            }
+        }
+        let mut body = None;
+        if let Some(params) = service.params {
+            match service.encoding {
+                Encoding::Query => {
+                    let query = serde_urlencoded::to_string(&params).map_err(|e| invalid(&e))?;
+                    let query = match url.query() {
+                        Some(auth) if !query.is_empty() => format!("{}&{}", auth, query),
+                        Some(auth) => auth.to_string(),
+                        None => query,
+                    };
+                    url.set_query(Some(&query).filter(|q| !q.is_empty()).map(|q| q.as_str()));
+                }
+                Encoding::Form => {
+                    let form = serde_urlencoded::to_string(&params).map_err(|e| invalid(&e))?;
+                    headers.insert(
+                        CONTENT_TYPE,
+                        HeaderValue::from_static("application/x-www-form-urlencoded"),
+                    );
+                    body = Some(form.into_bytes());
+                }
+                Encoding::Json => {
+                    headers.insert(CONTENT_TYPE, HeaderValue::from_static("application/json"));
+                    body = Some(serde_json::to_vec(&json_body(&params))?);
+                }
+            }
+        }
+        Ok(HttpRequest {
+            method: service.method,
+            url,
+            headers,
+            body,
+        })
+    }
+
+    /// Reads the rate limit headers, and makes the error for a non-success status.
+    fn check(&self, response: HttpResponse) -> Result<HttpResponse, Error> {
+        if let Some(limiter) = &self.rate_limit {
+            limiter.update(&response.headers);
+        }
+        // 304 is only returned to the revalidation of a cached response.
+        if !response.status.is_success() && response.status != StatusCode::NOT_MODIFIED {
+            return Err(Error::from_response(
+                response.status,
+                &response.headers,
+                &response.text(),
+            ));
+        }
+        Ok(response)
+    }
+}
+
+impl Call<'_> {
+    /// `sent` is the result of the last `Step::Send`, or `None` after any other step.
+    pub(crate) fn step(&mut self, sent: Option<Result<HttpResponse, Error>>) -> Step {
+        if let Some(sent) = sent {
+            return match sent.and_then(|response| self.core.check(response)) {
+                Ok(response) => Step::Done(Ok(self.finish(response))),
+                Err(e) => {
+                    let delay = self
+                        .core
+                        .retry
+                        .retry_delay(&self.request.method, self.attempt, &e);
+                    match delay {
+                        Some(delay) => {
+                            self.attempt += 1;
+                            self.waited = false;
+                            Step::Sleep(delay)
+                        }
+                        None => Step::Done(Err(e)),
+                    }
+                }
+            };
+        }
+        if let Lookup::Hit(_) = self.lookup {
+            if let Lookup::Hit(cached) = std::mem::replace(&mut self.lookup, Lookup::Skip) {
+                return Step::Done(Ok(cached.into()));
+            }
+        }
+        if !self.waited {
+            self.waited = true;
+            if let Some(limiter) = &self.core.rate_limit {
+                let delay = limiter.acquire();
+                if !delay.is_zero() {
+                    return Step::Sleep(delay);
+                }
+            }
+        }
+        Step::Send(self.request.clone())
+    }
+
+    /// Keeps the response in the cache, or takes the cached one on `304 Not Modified`.
+    fn finish(&mut self, response: HttpResponse) -> HttpResponse {
+        let cache = match &self.core.cache {
+            Some(cache) => cache,
+            None => return response,
+        };
+        match std::mem::replace(&mut self.lookup, Lookup::Skip) {
+            Lookup::Revalidate(key, cached) if response.status == StatusCode::NOT_MODIFIED => {
+                cache.refresh(&key, cached).into()
+            }
+            Lookup::Revalidate(key, _) | Lookup::Miss(key) => {
+                cache.save(&key, &response);
+                response
+            }
+            _ => response,
+        }
+    }
+}
@@ -1,36 +1,47 @@
-use crate::Service;
+use crate::transport::HttpRequest;
+use crate::{
+    join_url, Encoding, Error, ErrorKind, Info, Revoke, Service, API_BASE_URL, SITE_BASE_URL,
+};
+#[cfg(feature = "async")]
+use crate::transport::AsyncTransport;
+#[cfg(feature = "blocking")]
+use crate::transport::Transport;
+#[cfg(any(feature = "blocking", feature = "async"))]
+use crate::{decode_token, models::TokenResponse, TokenStore};
+use reqwest::header::{HeaderMap, HeaderValue, CONTENT_TYPE};
 use reqwest::{Method, Url};
-use reqwest::blocking::{Client};
-use serde_json::Value;
 
 /// A make request to autholize.
 
 #[derive(Debug, Clone)]
 pub struct OAuth {
     pub client_id: String,
+    pub base_url: String,
+    pub authorize_base_url: String,
 }
 
 /// To make Authorize URL.
 
-#[derive(Debug, PartialEq)]
+#[derive(Debug, Clone, PartialEq)]
 pub struct AuthorizeUrl {
     pub client_id: String,
     pub redirect_uri: String,
     pub scope: String,
+    pub state: Option<String>,
+    pub base_url: String,
 }
 
 /// A request to get Annict access token.
 
-#[derive(Debug, PartialEq)]
+#[derive(Debug, Clone, PartialEq)]
 pub struct AccessToken {
     pub client_id: String,
     pub client_secret: String,
     pub redirect_uri: String,
     pub code: String,
+    pub base_url: String,
 }
 
-type Revoke = String;
-
 impl OAuth {
     pub fn client_id<P>(client_id: P) -> OAuth
     where
@@ -38,6 +49,30 @@ impl OAuth {
     {
         OAuth {
             client_id: client_id.into(),
+            base_url: API_BASE_URL.to_string(),
+            authorize_base_url: SITE_BASE_URL.to_string(),
+        }
+    }
+
+    /// Request the access token to `base_url` instead of https://api.annict.com
+    pub fn base_url<P>(self, base_url: P) -> OAuth
+    where
+        P: Into<String>,
+    {
+        OAuth {
+            base_url: base_url.into(),
+            ..self
+        }
+    }
+
+    /// Make the authorize URL on `authorize_base_url` instead of https://annict.com
+    pub fn authorize_base_url<P>(self, authorize_base_url: P) -> OAuth
+    where
+        P: Into<String>,
+    {
+        OAuth {
+            authorize_base_url: authorize_base_url.into(),
+            ..self
         }
     }
 
@@ -46,31 +81,38 @@ impl OAuth {
     /// Examples
     /// ========
     /// ```rust
+    /// # #[cfg(feature = "blocking")]
+    /// # {
     /// # use annis::{Client, OAuth, Error};
     /// #
     /// # fn run() -> Result<(), Error> {
     /// let client = Client::set_token("annict_access_token");
     ///
-    /// let info = OAuth::info();
+    /// let info = client.fetch(OAuth::info())?;
     ///
-    /// client.call(info)?;
+    /// println!("{}", info.resource_owner_id);
     /// # Ok(())
     /// # }
+    /// # }
     /// ```
 
-    pub fn info() -> Service<String> {
+    pub fn info() -> Service<Info> {
         Service {
             method: Method::GET,
-            url: "https://api.annict.com/oauth/token/info".to_string(),
+            path: "/oauth/token/info".to_string(),
             params: None,
+            encoding: Encoding::Query,
         }
     }
 
-    /// Request to /oauth/revoke
+    /// Request to /oauth/revoke   
+    /// `Client::revoke()` revokes the client's own token and also clears its TokenStore.
     ///
     /// Examples
     /// ========
     /// ```rust
+    /// # #[cfg(feature = "blocking")]
+    /// # {
     /// # use annis::{Client, OAuth, Error};
     /// #
     /// # fn run() -> Result<(), Error> {
@@ -78,9 +120,10 @@ impl OAuth {
     ///
     /// let revoke = OAuth::revoke("revoke_access_token");
     ///
-    /// client.call(revoke)?;
+    /// client.fetch(revoke)?;
     /// # Ok(())
     /// # }
+    /// # }
     /// ```
 
     pub fn revoke<A>(access_token: A) -> Service<Revoke>
@@ -89,26 +132,35 @@ impl OAuth {
     {
         Service {
             method: Method::POST,
-            url: "https://api.annict.com/oauth/revoke".to_string(),
-            params: Some(vec![("token".to_string(), access_token.into())]),
+            path: "/oauth/revoke".to_string(),
+            params: Some(vec![(Revoke::token, access_token.into())]),
+            encoding: Encoding::Form,
         }
     }
 
     pub fn authorize_url(&self) -> AuthorizeUrl {
-        AuthorizeUrl::new(self.clone().client_id)
+        AuthorizeUrl {
+            base_url: self.authorize_base_url.clone(),
+            ..AuthorizeUrl::new(self.clone().client_id)
+        }
     }
 
     pub fn access_token(&self) -> AccessToken {
-        AccessToken::new(self.clone().client_id)
+        AccessToken {
+            base_url: self.base_url.clone(),
+            ..AccessToken::new(self.clone().client_id)
+        }
     }
 }
 
 impl AuthorizeUrl {
     pub fn new(client_id: String) -> Self {
         AuthorizeUrl {
-            client_id: client_id,
+            client_id,
             redirect_uri: "urn:ietf:wg:oauth:2.0:oob".into(),
             scope: "read".into(),
+            state: None,
+            base_url: SITE_BASE_URL.into(),
         }
     }
 
@@ -128,18 +180,49 @@ impl AuthorizeUrl {
         self
     }
 
+    /// Send `state` to be verified on the redirect with `annis::verify_callback()`.   
+    /// `annis::random_state()` makes an unguessable one.
+    pub fn state<P>(&mut self, state: P) -> &mut Self
+    where
+        P: Into<String>,
+    {
+        self.state = Some(state.into());
+        self
+    }
+
+    /// # Panics
+    /// Panics if `base_url` is not a valid URL. `try_build()` returns the error instead.
     pub fn build(&self) -> String {
-        format!("https://annict.com/oauth/authorize?client_id={}&redirect_uri={}&response_type=code&scope={}",self.client_id, self.redirect_uri, self.scope)
+        self.try_build().expect("invalid base_url")
+    }
+
+    /// Make the authorize URL, or an `InvalidParameter` error if `base_url` is not a valid URL.
+    pub fn try_build(&self) -> Result<String, Error> {
+        // "read+write" was once written pre-encoded.
+        let scope = self.scope.replace('+', " ");
+        let mut params = vec![
+            ("client_id", self.client_id.as_str()),
+            ("redirect_uri", self.redirect_uri.as_str()),
+            ("response_type", "code"),
+            ("scope", scope.as_str()),
+        ];
+        if let Some(state) = &self.state {
+            params.push(("state", state));
+        }
+        let url = Url::parse_with_params(&join_url(&self.base_url, "/oauth/authorize"), &params)
+            .map_err(|e| ErrorKind::InvalidParameter(e.to_string()))?;
+        Ok(url.into())
     }
 }
 
 impl AccessToken {
     pub fn new(client_id: String) -> Self {
         AccessToken {
-            client_id: client_id.into(),
+            client_id,
             client_secret: "".into(),
             code: "".into(),
             redirect_uri: "urn:ietf:wg:oauth:2.0:oob".into(),
+            base_url: API_BASE_URL.into(),
         }
     }
 
@@ -167,7 +250,61 @@ impl AccessToken {
         self
     }
 
-    pub fn build(&self) -> String {
+    /// Exchange the code for an access token.
+    #[cfg(feature = "blocking")]
+    pub fn build(&self) -> Result<TokenResponse, Error> {
+        self.build_with(&reqwest::blocking::Client::new())
+    }
+
+    /// Exchange the code for an access token, sending the request with `transport`.
+    #[cfg(feature = "blocking")]
+    pub fn build_with<T>(&self, transport: &T) -> Result<TokenResponse, Error>
+    where
+        T: Transport + ?Sized,
+    {
+        let response = transport.send(self.request()?)?;
+        decode_token(response.status, &response.headers, &response.text())
+    }
+
+    /// Exchange the code for an access token and save it to `store`.
+    #[cfg(feature = "blocking")]
+    pub fn build_and_save<S>(&self, store: &S) -> Result<TokenResponse, Error>
+    where
+        S: TokenStore + ?Sized,
+    {
+        let token = self.build()?;
+        store.save(&token)?;
+        Ok(token)
+    }
+
+    /// Exchange the code for an access token without blocking.
+    #[cfg(feature = "async")]
+    pub async fn build_async(&self) -> Result<TokenResponse, Error> {
+        self.build_with_async(&reqwest::Client::new()).await
+    }
+
+    /// Exchange the code for an access token without blocking, sending the request with `transport`.
+    #[cfg(feature = "async")]
+    pub async fn build_with_async<T>(&self, transport: &T) -> Result<TokenResponse, Error>
+    where
+        T: AsyncTransport + ?Sized,
+    {
+        let response = transport.send(self.request()?).await?;
+        decode_token(response.status, &response.headers, &response.text())
+    }
+
+    /// Exchange the code for an access token without blocking and save it to `store`.
+    #[cfg(feature = "async")]
+    pub async fn build_and_save_async<S>(&self, store: &S) -> Result<TokenResponse, Error>
+    where
+        S: TokenStore + ?Sized,
+    {
+        let token = self.build_async().await?;
+        store.save(&token)?;
+        Ok(token)
+    }
+
+    pub(crate) fn request(&self) -> Result<HttpRequest, Error> {
         let params: Vec<(&str, &str)> = vec![
             ("client_id", &self.client_id),
             ("client_secret", &self.client_secret),
@@ -175,16 +312,20 @@ impl AccessToken {
             ("redirect_uri", &self.redirect_uri),
             ("code", &self.code),
         ];
-        let uri = Url::parse_with_params("https://api.annict.com/oauth/token", &params).unwrap();
-        Client::new()
-            .post(uri.as_str())
-            .form(&params)
-            .send()
-            .unwrap()
-            .json::<Value>()
-            .unwrap()["access_token"]
-            .to_string()
-            .trim_matches('\"')
-            .to_string()
+        let url = join_url(&self.base_url, "/oauth/token");
+        let url = Url::parse(&url).map_err(|e| ErrorKind::InvalidParameter(e.to_string()))?;
+        let mut headers = HeaderMap::new();
+        headers.insert(
+            CONTENT_TYPE,
+            HeaderValue::from_static("application/x-www-form-urlencoded"),
+        );
+        let body = serde_urlencoded::to_string(&params)
+            .map_err(|e| ErrorKind::InvalidParameter(e.to_string()))?;
+        Ok(HttpRequest {
+            method: Method::POST,
+            url,
+            headers,
+            body: Some(body.into_bytes()),
+        })
     }
 }
@@ -0,0 +1,319 @@
+use crate::api::Core;
+#[cfg(feature = "async")]
+use crate::transport::AsyncTransport;
+#[cfg(feature = "blocking")]
+use crate::transport::Transport;
+use crate::{
+    AuthMethod, ProxyConfig, RateLimiter, ResponseCache, RetryPolicy, API_BASE_URL, USER_AGENT,
+};
+use std::fmt;
+use std::marker::PhantomData;
+use std::sync::Arc;
+use std::time::Duration;
+
+/// A builder to make Client.
+/// `ClientBuilder` makes `Client`, and `nonblocking::ClientBuilder` makes `nonblocking::Client`.
+///
+/// Examples
+/// ========
+/// ```rust
+/// # #[cfg(feature = "blocking")]
+/// # {
+/// # use annis::Client;
+/// use std::time::Duration;
+///
+/// let client = Client::builder()
+///     .token("access_token")
+///     .base_url("http://127.0.0.1:3000")
+///     .timeout(Duration::from_secs(10))
+///     .pool_max_idle_per_host(4)
+///     .proxy("http://proxy.example.com:8080")
+///     .user_agent("my-app/1.0")
+///     .default_header("X-Request-Source", "sync")
+///     .build();
+/// # }
+///```
+
+pub struct ClientBuilder<C = DefaultClient> {
+    pub(crate) token: String,
+    base_url: String,
+    auth_method: AuthMethod,
+    retry: RetryPolicy,
+    rate_limit: Option<RateLimiter>,
+    cache: Option<ResponseCache>,
+    timeout: Option<Duration>,
+    connect_timeout: Option<Duration>,
+    pool_idle_timeout: Option<Duration>,
+    pool_max_idle_per_host: Option<usize>,
+    proxies: Vec<ProxyConfig>,
+    user_agent: String,
+    default_headers: Vec<(String, String)>,
+    gzip: bool,
+    brotli: bool,
+    #[cfg(feature = "blocking")]
+    pub(crate) transport: Option<Arc<dyn Transport>>,
+    #[cfg(feature = "async")]
+    pub(crate) async_transport: Option<Arc<dyn AsyncTransport>>,
+    client: PhantomData<fn() -> C>,
+}
+
+/// What `ClientBuilder` makes without a type parameter.
+#[cfg(feature = "blocking")]
+type DefaultClient = crate::Client;
+#[cfg(not(feature = "blocking"))]
+type DefaultClient = crate::nonblocking::Client;
+
+/// Makes a reqwest client of either kind from the settings of a ClientBuilder.
+macro_rules! http_client {
+    ($http:expr, $builder:expr) => {{
+        let builder = $builder;
+        let mut http = $http
+            .user_agent(builder.user_agent.as_str())
+            .default_headers(crate::header_map(&builder.default_headers)?)
+            .gzip(builder.gzip)
+            .brotli(builder.brotli);
+        for proxy in &builder.proxies {
+            http = http.proxy(proxy.build()?);
+        }
+        if let Some(timeout) = builder.timeout {
+            http = http.timeout(timeout);
+        }
+        if let Some(timeout) = builder.connect_timeout {
+            http = http.connect_timeout(timeout);
+        }
+        if let Some(timeout) = builder.pool_idle_timeout {
+            http = http.pool_idle_timeout(timeout);
+        }
+        if let Some(max) = builder.pool_max_idle_per_host {
+            http = http.pool_max_idle_per_host(max);
+        }
+        http.build()
+    }};
+}
+
+impl<C> ClientBuilder<C> {
+    pub fn token<T>(&mut self, access_token: T) -> &mut Self
+    where
+        T: Into<String>,
+    {
+        self.token = access_token.into();
+        self
+    }
+
+    /// Send requests to `base_url` instead of https://api.annict.com
+    pub fn base_url<T>(&mut self, base_url: T) -> &mut Self
+    where
+        T: Into<String>,
+    {
+        self.base_url = base_url.into();
+        self
+    }
+
+    /// Send the token as the `access_token` query parameter instead of the `Authorization` header.
+    pub fn auth_method(&mut self, auth_method: AuthMethod) -> &mut Self {
+        self.auth_method = auth_method;
+        self
+    }
+
+    /// Send failed requests again as `retry` allows.
+    pub fn retry(&mut self, retry: RetryPolicy) -> &mut Self {
+        self.retry = retry;
+        self
+    }
+
+    /// Wait for `limiter` before each request.
+    pub fn rate_limit(&mut self, limiter: RateLimiter) -> &mut Self {
+        self.rate_limit = Some(limiter);
+        self
+    }
+
+    /// Keep GET responses in `cache`.
+    pub fn cache(&mut self, cache: ResponseCache) -> &mut Self {
+        self.cache = Some(cache);
+        self
+    }
+
+    /// Timeout of a whole request, from connecting until the body is read.
+    pub fn timeout(&mut self, timeout: Duration) -> &mut Self {
+        self.timeout = Some(timeout);
+        self
+    }
+
+    pub fn connect_timeout(&mut self, timeout: Duration) -> &mut Self {
+        self.connect_timeout = Some(timeout);
+        self
+    }
+
+    /// How long an idle connection is kept in the pool.
+    pub fn pool_idle_timeout(&mut self, timeout: Duration) -> &mut Self {
+        self.pool_idle_timeout = Some(timeout);
+        self
+    }
+
+    /// How many idle connections are kept in the pool for each host.
+    pub fn pool_max_idle_per_host(&mut self, max: usize) -> &mut Self {
+        self.pool_max_idle_per_host = Some(max);
+        self
+    }
+
+    /// Send requests of every scheme through the proxy at `url`.
+    pub fn proxy<T>(&mut self, url: T) -> &mut Self
+    where
+        T: Into<String>,
+    {
+        self.proxies.push(ProxyConfig::All(url.into()));
+        self
+    }
+
+    /// Send `http://` requests through the proxy at `url`.
+    pub fn http_proxy<T>(&mut self, url: T) -> &mut Self
+    where
+        T: Into<String>,
+    {
+        self.proxies.push(ProxyConfig::Http(url.into()));
+        self
+    }
+
+    /// Send `https://` requests through the proxy at `url`.
+    pub fn https_proxy<T>(&mut self, url: T) -> &mut Self
+    where
+        T: Into<String>,
+    {
+        self.proxies.push(ProxyConfig::Https(url.into()));
+        self
+    }
+
+    /// Send `user_agent` instead of `annis/<version>`.
+    pub fn user_agent<T>(&mut self, user_agent: T) -> &mut Self
+    where
+        T: Into<String>,
+    {
+        self.user_agent = user_agent.into();
+        self
+    }
+
+    /// Send a header with every request.
+    pub fn default_header<K, V>(&mut self, name: K, value: V) -> &mut Self
+    where
+        K: Into<String>,
+        V: Into<String>,
+    {
+        self.default_headers.push((name.into(), value.into()));
+        self
+    }
+
+    /// Accept and decompress gzip responses. Enabled by default.
+    pub fn gzip(&mut self, enable: bool) -> &mut Self {
+        self.gzip = enable;
+        self
+    }
+
+    /// Accept and decompress brotli responses. Enabled by default.
+    pub fn brotli(&mut self, enable: bool) -> &mut Self {
+        self.brotli = enable;
+        self
+    }
+
+    pub(crate) fn core(&self) -> Core {
+        Core {
+            base_url: self.base_url.clone(),
+            auth_method: self.auth_method,
+            retry: self.retry.clone(),
+            rate_limit: self.rate_limit.clone(),
+            cache: self.cache.clone(),
+            store: None,
+        }
+    }
+
+    #[cfg(feature = "blocking")]
+    pub(crate) fn blocking_http(&self) -> Result<reqwest::blocking::Client, crate::Error> {
+        Ok(http_client!(reqwest::blocking::Client::builder(), self)?)
+    }
+
+    #[cfg(feature = "async")]
+    pub(crate) fn async_http(&self) -> Result<reqwest::Client, crate::Error> {
+        Ok(http_client!(reqwest::Client::builder(), self)?)
+    }
+}
+
+impl<C> Default for ClientBuilder<C> {
+    fn default() -> Self {
+        ClientBuilder {
+            token: String::new(),
+            base_url: API_BASE_URL.to_string(),
+            auth_method: AuthMethod::default(),
+            retry: RetryPolicy::never(),
+            rate_limit: None,
+            cache: None,
+            timeout: None,
+            connect_timeout: None,
+            pool_idle_timeout: None,
+            pool_max_idle_per_host: None,
+            proxies: vec![],
+            user_agent: USER_AGENT.to_string(),
+            default_headers: vec![],
+            gzip: true,
+            brotli: true,
+            #[cfg(feature = "blocking")]
+            transport: None,
+            #[cfg(feature = "async")]
+            async_transport: None,
+            client: PhantomData,
+        }
+    }
+}
+
+impl<C> Clone for ClientBuilder<C> {
+    fn clone(&self) -> Self {
+        ClientBuilder {
+            token: self.token.clone(),
+            base_url: self.base_url.clone(),
+            auth_method: self.auth_method,
+            retry: self.retry.clone(),
+            rate_limit: self.rate_limit.clone(),
+            cache: self.cache.clone(),
+            timeout: self.timeout,
+            connect_timeout: self.connect_timeout,
+            pool_idle_timeout: self.pool_idle_timeout,
+            pool_max_idle_per_host: self.pool_max_idle_per_host,
+            proxies: self.proxies.clone(),
+            user_agent: self.user_agent.clone(),
+            default_headers: self.default_headers.clone(),
+            gzip: self.gzip,
+            brotli: self.brotli,
+            #[cfg(feature = "blocking")]
+            transport: self.transport.clone(),
+            #[cfg(feature = "async")]
+            async_transport: self.async_transport.clone(),
+            client: PhantomData,
+        }
+    }
+}
+
+// The token is left out so that logging a builder doesn't leak it.
+impl<C> fmt::Debug for ClientBuilder<C> {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        let mut debug = f.debug_struct("ClientBuilder");
+        debug
+            .field("token", &"<redacted>")
+            .field("base_url", &self.base_url)
+            .field("auth_method", &self.auth_method)
+            .field("retry", &self.retry)
+            .field("rate_limit", &self.rate_limit)
+            .field("cache", &self.cache)
+            .field("timeout", &self.timeout)
+            .field("connect_timeout", &self.connect_timeout)
+            .field("pool_idle_timeout", &self.pool_idle_timeout)
+            .field("pool_max_idle_per_host", &self.pool_max_idle_per_host)
+            .field("proxies", &self.proxies)
+            .field("user_agent", &self.user_agent)
+            .field("default_headers", &self.default_headers)
+            .field("gzip", &self.gzip)
+            .field("brotli", &self.brotli);
+        #[cfg(feature = "blocking")]
+        debug.field("transport", &self.transport);
+        #[cfg(feature = "async")]
+        debug.field("async_transport", &self.async_transport);
+        debug.finish()
+    }
+}
@@ -0,0 +1,387 @@
+use crate::store::replace_file;
+use crate::transport::HttpResponse;
+use crate::Error;
+use reqwest::header::{
+    HeaderMap, HeaderName, HeaderValue, ETAG, IF_MODIFIED_SINCE, IF_NONE_MATCH, LAST_MODIFIED,
+};
+use reqwest::{Method, StatusCode, Url};
+use serde::{Deserialize, Serialize};
+use std::collections::{HashMap, VecDeque};
+use std::fmt::Debug;
+use std::fs;
+use std::io::ErrorKind as IoErrorKind;
+use std::path::{Path, PathBuf};
+use std::sync::{Arc, Mutex};
+use std::time::{Duration, SystemTime, UNIX_EPOCH};
+
+/// A cache of GET responses. Set with `ClientBuilder::cache()`.
+///
+/// Responses are keyed by the URL with its parameters, and the access token.
+/// A response younger than `ttl` is returned without a request.
+/// An older one is revalidated with `If-None-Match` and `If-Modified-Since`
+/// when the server sent an `ETag` or `Last-Modified`, and reused on `304 Not Modified`.
+///
+/// Examples
+/// ========
+/// ```rust
+/// # #[cfg(feature = "blocking")]
+/// # {
+/// # use annis::{Client, ResponseCache};
+/// use std::time::Duration;
+///
+/// let client = Client::builder()
+///     .token("access_token")
+///     .cache(ResponseCache::memory(1000).ttl(Duration::from_secs(600)))
+///     .build();
+/// # }
+/// ```
+
+#[derive(Debug, Clone)]
+pub struct ResponseCache {
+    store: Arc<dyn CacheStore>,
+    ttl: Duration,
+}
+
+/// A place to keep cached responses.
+/// Errors of the store are not returned to callers of the client; a failed `get` is a miss.
+
+pub trait CacheStore: Debug + Send + Sync {
+    fn get(&self, key: &str) -> Result<Option<CachedResponse>, Error>;
+    fn put(&self, key: &str, response: &CachedResponse) -> Result<(), Error>;
+    fn remove(&self, key: &str) -> Result<(), Error>;
+}
+
+/// A response kept by a CacheStore.
+
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct CachedResponse {
+    pub status: u16,
+    pub headers: Vec<(String, String)>,
+    pub body: Vec<u8>,
+    /// When the response was received or last revalidated, in milliseconds since the UNIX epoch.
+    pub stored_at: u64,
+}
+
+/// A CacheStore which keeps up to `capacity` responses in memory,
+/// dropping the least recently used one first.
+
+#[derive(Debug)]
+pub struct MemoryCache {
+    capacity: usize,
+    entries: Mutex<(HashMap<String, CachedResponse>, VecDeque<String>)>,
+}
+
+/// A CacheStore which writes each response to a JSON file in a directory.
+/// The files and the directory it creates are readable only by the owner.
+
+#[derive(Debug, Clone)]
+pub struct DiskCache {
+    dir: PathBuf,
+}
+
+/// What a client does with a request, decided by `ResponseCache::lookup()`.
+pub(crate) enum Lookup {
+    /// Not cacheable.
+    Skip,
+    /// Not cached yet.
+    Miss(String),
+    /// Cached and stale; the request now has validators.
+    Revalidate(String, CachedResponse),
+    /// Cached and fresh.
+    Hit(CachedResponse),
+}
+
+impl ResponseCache {
+    /// Caches responses with `store` for 60 seconds.
+    pub fn new<S>(store: S) -> Self
+    where
+        S: CacheStore + 'static,
+    {
+        ResponseCache {
+            store: Arc::new(store),
+            ttl: Duration::from_secs(60),
+        }
+    }
+
+    /// Caches up to `capacity` responses in memory.
+    pub fn memory(capacity: usize) -> Self {
+        ResponseCache::new(MemoryCache::new(capacity))
+    }
+
+    /// Caches responses in files under `dir`.
+    pub fn disk<P: Into<PathBuf>>(dir: P) -> Self {
+        ResponseCache::new(DiskCache::new(dir))
+    }
+
+    /// How long a response is used without asking the server.
+    /// Zero always revalidates.
+    pub fn ttl(self, ttl: Duration) -> Self {
+        ResponseCache { ttl, ..self }
+    }
+
+    pub fn store(&self) -> &dyn CacheStore {
+        self.store.as_ref()
+    }
+
+    /// Decides whether a request is served from the cache, and adds validators to `headers`.
+    pub(crate) fn lookup(
+        &self,
+        method: &Method,
+        url: &Url,
+        token: &str,
+        headers: &mut HeaderMap,
+    ) -> Lookup {
+        if *method != Method::GET {
+            return Lookup::Skip;
+        }
+        let key = cache_key(url, token);
+        let entry = match self.store.get(&key) {
+            Ok(Some(entry)) => entry,
+            _ => return Lookup::Miss(key),
+        };
+        if now_millis().saturating_sub(entry.stored_at) < self.ttl.as_millis() as u64 {
+            return Lookup::Hit(entry);
+        }
+        let mut validated = false;
+        if let Some(etag) = entry
+            .header(ETAG.as_str())
+            .and_then(|v| HeaderValue::from_str(v).ok())
+        {
+            headers.insert(IF_NONE_MATCH, etag);
+            validated = true;
+        }
+        if let Some(date) = entry
+            .header(LAST_MODIFIED.as_str())
+            .and_then(|v| HeaderValue::from_str(v).ok())
+        {
+            headers.insert(IF_MODIFIED_SINCE, date);
+            validated = true;
+        }
+        if validated {
+            Lookup::Revalidate(key, entry)
+        } else {
+            Lookup::Miss(key)
+        }
+    }
+
+    /// Keeps a cached response for another `ttl` after `304 Not Modified`.
+    pub(crate) fn refresh(&self, key: &str, mut entry: CachedResponse) -> CachedResponse {
+        entry.stored_at = now_millis();
+        let _ = self.store.put(key, &entry);
+        entry
+    }
+
+    /// Keeps a new response if it can be reused.
+    pub(crate) fn save(&self, key: &str, response: &HttpResponse) {
+        let entry = CachedResponse {
+            status: response.status.as_u16(),
+            headers: response
+                .headers
+                .iter()
+                .filter(|(name, _)| !SKIPPED_HEADERS.contains(&name.as_str()))
+                .filter_map(|(name, value)| {
+                    Some((name.to_string(), value.to_str().ok()?.to_string()))
+                })
+                .collect(),
+            body: response.body.clone(),
+            stored_at: now_millis(),
+        };
+        let no_store = entry
+            .header("cache-control")
+            .is_some_and(|v| v.contains("no-store"));
+        let reusable = !self.ttl.is_zero()
+            || entry.header(ETAG.as_str()).is_some()
+            || entry.header(LAST_MODIFIED.as_str()).is_some();
+        if response.status == StatusCode::OK && !no_store && reusable {
+            let _ = self.store.put(key, &entry);
+        }
+    }
+}
+
+/// Headers which describe the bytes on the wire rather than the body kept.
+const SKIPPED_HEADERS: [&str; 4] = [
+    "connection",
+    "content-encoding",
+    "content-length",
+    "transfer-encoding",
+];
+
+impl CachedResponse {
+    pub fn header(&self, name: &str) -> Option<&str> {
+        self.headers
+            .iter()
+            .find(|(k, _)| k.eq_ignore_ascii_case(name))
+            .map(|(_, v)| v.as_str())
+    }
+}
+
+impl From<CachedResponse> for HttpResponse {
+    fn from(cached: CachedResponse) -> HttpResponse {
+        let mut headers = HeaderMap::new();
+        for (name, value) in &cached.headers {
+            if let (Ok(name), Ok(value)) = (
+                HeaderName::from_bytes(name.as_bytes()),
+                HeaderValue::from_str(value),
+            ) {
+                headers.append(name, value);
+            }
+        }
+        HttpResponse {
+            status: StatusCode::from_u16(cached.status).unwrap_or(StatusCode::OK),
+            headers,
+            body: cached.body,
+        }
+    }
+}
+
+impl MemoryCache {
+    pub fn new(capacity: usize) -> Self {
+        MemoryCache {
+            capacity,
+            entries: Mutex::new((HashMap::new(), VecDeque::new())),
+        }
+    }
+
+    pub fn len(&self) -> usize {
+        self.lock().0.len()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.len() == 0
+    }
+
+    fn lock(
+        &self,
+    ) -> std::sync::MutexGuard<'_, (HashMap<String, CachedResponse>, VecDeque<String>)> {
+        self.entries.lock().unwrap_or_else(|e| e.into_inner())
+    }
+}
+
+impl CacheStore for MemoryCache {
+    fn get(&self, key: &str) -> Result<Option<CachedResponse>, Error> {
+        let mut entries = self.lock();
+        let (map, order) = &mut *entries;
+        let entry = map.get(key).cloned();
+        if entry.is_some() {
+            order.retain(|k| k != key);
+            order.push_back(key.to_string());
+        }
+        Ok(entry)
+    }
+
+    fn put(&self, key: &str, response: &CachedResponse) -> Result<(), Error> {
+        let mut entries = self.lock();
+        let (map, order) = &mut *entries;
+        order.retain(|k| k != key);
+        order.push_back(key.to_string());
+        map.insert(key.to_string(), response.clone());
+        while map.len() > self.capacity {
+            match order.pop_front() {
+                Some(oldest) => map.remove(&oldest),
+                None => break,
+            };
+        }
+        Ok(())
+    }
+
+    fn remove(&self, key: &str) -> Result<(), Error> {
+        let mut entries = self.lock();
+        let (map, order) = &mut *entries;
+        map.remove(key);
+        order.retain(|k| k != key);
+        Ok(())
+    }
+}
+
+/// The file content of DiskCache, which keeps the key to tell apart hash collisions.
+#[derive(Serialize, Deserialize)]
+struct DiskEntry {
+    key: String,
+    response: CachedResponse,
+}
+
+impl DiskCache {
+    pub fn new<P: Into<PathBuf>>(dir: P) -> Self {
+        DiskCache { dir: dir.into() }
+    }
+
+    pub fn dir(&self) -> &Path {
+        &self.dir
+    }
+
+    fn path(&self, key: &str) -> PathBuf {
+        self.dir
+            .join(format!("{:016x}.json", fnv1a(key.as_bytes())))
+    }
+}
+
+impl CacheStore for DiskCache {
+    fn get(&self, key: &str) -> Result<Option<CachedResponse>, Error> {
+        let json = match fs::read(self.path(key)) {
+            Ok(json) => json,
+            Err(e) if e.kind() == IoErrorKind::NotFound => return Ok(None),
+            Err(e) => return Err(e.into()),
+        };
+        let entry: DiskEntry = serde_json::from_slice(&json)?;
+        if entry.key == key {
+            Ok(Some(entry.response))
+        } else {
+            Ok(None)
+        }
+    }
+
+    fn put(&self, key: &str, response: &CachedResponse) -> Result<(), Error> {
+        let mut dir = fs::DirBuilder::new();
+        dir.recursive(true);
+        #[cfg(unix)]
+        {
+            use std::os::unix::fs::DirBuilderExt;
+            dir.mode(0o700);
+        }
+        dir.create(&self.dir)?;
+        let entry = DiskEntry {
+            key: key.to_string(),
+            response: response.clone(),
+        };
+        replace_file(&self.path(key), &serde_json::to_vec(&entry)?)?;
+        Ok(())
+    }
+
+    fn remove(&self, key: &str) -> Result<(), Error> {
+        match fs::remove_file(self.path(key)) {
+            Err(e) if e.kind() != IoErrorKind::NotFound => Err(e.into()),
+            _ => Ok(()),
+        }
+    }
+}
+
+/// `GET <url>` without the `access_token` parameter, and a hash of the token,
+/// so that a cache shared by users doesn't return one's responses to another.
+fn cache_key(url: &Url, token: &str) -> String {
+    let mut url = url.clone();
+    let params: Vec<(String, String)> = url
+        .query_pairs()
+        .filter(|(k, _)| k != "access_token")
+        .map(|(k, v)| (k.into_owned(), v.into_owned()))
+        .collect();
+    if params.is_empty() {
+        url.set_query(None);
+    } else {
+        url.query_pairs_mut().clear().extend_pairs(params);
+    }
+    format!("GET {} {:016x}", url, fnv1a(token.as_bytes()))
+}
+
+/// FNV-1a, which unlike the hasher of std gives the same hash on every run.
+fn fnv1a(bytes: &[u8]) -> u64 {
+    bytes.iter().fold(0xcbf2_9ce4_8422_2325, |hash, byte| {
+        (hash ^ u64::from(*byte)).wrapping_mul(0x0100_0000_01b3)
+    })
+}
+
+fn now_millis() -> u64 {
+    SystemTime::now()
+        .duration_since(UNIX_EPOCH)
+        .map(|d| d.as_millis() as u64)
+        .unwrap_or_default()
+}
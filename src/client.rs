@@ -1,7 +1,17 @@
-use crate::{Service, Error};
-use reqwest::blocking::{Client as SyncClinet};
+use crate::api::{Core, Step};
+use crate::models::Revoked;
+use crate::pagination::Cursor;
+use crate::transport::{HttpResponse, Transport};
+use crate::{
+    decode, Api, ClientBuilder, Endpoint, Error, ErrorKind, OAuth, Page, Paginate, RateLimiter,
+    Service, TokenStore,
+};
+use reqwest::blocking::Response;
 use serde::Serialize;
 use std::cmp::PartialEq;
+use std::fmt;
+use std::sync::Arc;
+use std::thread;
 
 /// A client to make request with Service.
 ///
@@ -12,14 +22,19 @@ use std::cmp::PartialEq;
 /// #
 /// # fn run() -> Result<(), Error> {
 /// let client = Client::set_token("access_token");
-/// let res = client.call(annis::works())?.json()?;
+/// let res = client.call(annis::works())?.json::<Value>()?;
 /// #   Ok(())
 /// # }
 ///```
+///
+/// The client keeps a pool of connections, which its clones share.
+/// Clone it instead of making a new one for each request.
 
-#[derive(Debug, Clone)]
+#[derive(Clone)]
 pub struct Client {
     pub token: String,
+    core: Core,
+    transport: Arc<dyn Transport>,
 }
 
 impl Client {
@@ -27,22 +42,258 @@ impl Client {
     where
         T: Into<String>,
     {
-        Client {
-            token: access_token.into(),
+        Client::builder().token(access_token).build()
+    }
+
+    pub fn builder() -> ClientBuilder {
+        ClientBuilder::default()
+    }
+
+    /// Make a client with the token saved in `store`.   
+    /// Returns `ErrorKind::MissingToken` if the store is empty.
+    pub fn from_store<S>(store: S) -> Result<Self, Error>
+    where
+        S: TokenStore + 'static,
+    {
+        Client::builder().build_with_store(store)
+    }
+
+    /// The base URL which paths of Service are joined to.
+    pub fn base_url(&self) -> &str {
+        &self.core.base_url
+    }
+
+    /// The RateLimiter set by `ClientBuilder::rate_limit()`.
+    /// It is shared by the clones of this client.
+    pub fn rate_limiter(&self) -> Option<&RateLimiter> {
+        self.core.rate_limit.as_ref()
+    }
+
+    /// Revoke the token of this client, and clear the store it was loaded from.
+    pub fn revoke(&self) -> Result<Revoked, Error> {
+        let revoked = self.fetch(OAuth::revoke(self.token.as_str()))?;
+        if let Some(store) = &self.core.store {
+            store.clear()?;
         }
+        Ok(revoked)
     }
 
-    pub fn call<K>(&self, service: Service<K>) -> Result<reqwest::blocking::Response, Error>
+    /// Make a request with Service.   
+    /// Returns `Err` if the server responds with a non-success status,
+    /// after the retries allowed by `ClientBuilder::retry()`.
+
+    pub fn call<K>(&self, service: Service<K>) -> Result<Response, Error>
     where
         K: Serialize + Into<String> + PartialEq,
     {
-        let mut client = SyncClinet::new()
-            .request(service.method, service.url.as_str())
-            .query(&vec![("access_token", self.clone().token)]);
-        if let Some(params) = service.params {
-            client = client.query(&params);
-        };
-        client.send().map_err(Into::into)
+        let response = self.send(service)?;
+        Ok(http::Response::from(response).into())
+    }
+
+    /// Drive a request of the Core through the transport.
+    fn send<K>(&self, service: Service<K>) -> Result<HttpResponse, Error>
+    where
+        K: Serialize + Into<String> + PartialEq,
+    {
+        let mut call = self.core.call(&self.token, service)?;
+        let mut sent = None;
+        loop {
+            match call.step(sent.take()) {
+                Step::Send(request) => sent = Some(self.transport.send(request)),
+                Step::Sleep(delay) => thread::sleep(delay),
+                Step::Done(response) => return response,
+            }
+        }
+    }
+
+    /// Make a request with Service and decode the response to the type tied to its parameter.
+    ///
+    /// Examples
+    /// ========
+    /// ```rust
+    /// # use annis::{Client, Error};
+    /// #
+    /// # fn run() -> Result<(), Error> {
+    /// let client = Client::set_token("access_token");
+    /// let res = client.fetch(annis::works().params(vec![("filter_title", "lain")]))?;
+    /// println!("{}", res.works[0].title);
+    /// #   Ok(())
+    /// # }
+    /// ```
+
+    pub fn fetch<K>(&self, service: Service<K>) -> Result<K::Response, Error>
+    where
+        K: Serialize + Into<String> + PartialEq + Endpoint,
+    {
+        decode(&self.send(service)?.text())
+    }
+
+    /// Make an iterator which yields every item of a list endpoint, following `next_page`.
+    ///
+    /// Examples
+    /// ========
+    /// ```rust
+    /// # use annis::{Client, Error};
+    /// #
+    /// # fn run() -> Result<(), Error> {
+    /// let client = Client::set_token("access_token");
+    /// let works = annis::works().params(vec![("filter_season", "2016-spring")]);
+    ///
+    /// for work in client.paginate(works).max_items(100) {
+    ///     println!("{}", work?.title);
+    /// }
+    /// #   Ok(())
+    /// # }
+    /// ```
+
+    pub fn paginate<K>(&self, service: Service<K>) -> Paginator<K>
+    where
+        K: Serialize + Into<String> + PartialEq + Paginate + Clone,
+        K::Response: Page,
+    {
+        Paginator {
+            client: self.clone(),
+            cursor: Cursor::new(service.page()),
+            service,
+        }
     }
 }
 
+/// An iterator over the items of a list endpoint. Made by `Client::paginate()`.
+
+pub struct Paginator<K>
+where
+    K: Into<String> + PartialEq + Endpoint,
+    K::Response: Page,
+{
+    client: Client,
+    service: Service<K>,
+    cursor: Cursor<<K::Response as Page>::Item>,
+}
+
+impl<K> Paginator<K>
+where
+    K: Into<String> + PartialEq + Endpoint,
+    K::Response: Page,
+{
+    /// Stop after yielding `max` items.
+    pub fn max_items(mut self, max: usize) -> Self {
+        self.cursor.max_items = Some(max);
+        self
+    }
+
+    /// Stop after requesting `max` pages.
+    pub fn max_pages(mut self, max: usize) -> Self {
+        self.cursor.max_pages = Some(max);
+        self
+    }
+}
+
+impl<K> Iterator for Paginator<K>
+where
+    K: Serialize + Into<String> + PartialEq + Paginate + Clone,
+    K::Response: Page,
+{
+    type Item = Result<<K::Response as Page>::Item, Error>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        loop {
+            if let Some(item) = self.cursor.next_item() {
+                return Some(Ok(item));
+            }
+            let page = self.cursor.take_page()?;
+            match self.client.fetch(self.service.with_page(page)) {
+                Ok(response) => self.cursor.push(response),
+                Err(e) => {
+                    self.cursor.finish();
+                    return Some(Err(e));
+                }
+            }
+        }
+    }
+}
+
+// The token is left out so that logging a client doesn't leak it.
+impl fmt::Debug for Client {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.debug_struct("Client")
+            .field("token", &"<redacted>")
+            .field("core", &self.core)
+            .field("transport", &self.transport)
+            .finish()
+    }
+}
+
+impl Api for Client {
+    type Output<T: Send + 'static> = Result<T, Error>;
+
+    fn fetch<K>(&self, service: Service<K>) -> Result<K::Response, Error>
+    where
+        K: Serialize + Into<String> + PartialEq + Endpoint + Send + 'static,
+        K::Response: Send + 'static,
+    {
+        Client::fetch(self, service)
+    }
+
+    fn fetch_all<K>(&self, service: Service<K>) -> Result<Vec<<K::Response as Page>::Item>, Error>
+    where
+        K: Serialize + Into<String> + PartialEq + Paginate + Clone + Send + 'static,
+        K::Response: Page + Send + 'static,
+        <K::Response as Page>::Item: Send + 'static,
+    {
+        self.paginate(service).collect()
+    }
+
+    fn map<T, U, F>(output: Result<T, Error>, f: F) -> Result<U, Error>
+    where
+        T: Send + 'static,
+        U: Send + 'static,
+        F: FnOnce(T) -> U + Send + 'static,
+    {
+        output.map(f)
+    }
+}
+
+impl ClientBuilder<Client> {
+    /// Send requests with `transport` instead of `reqwest::blocking::Client`.
+    /// Settings of the HTTP client, e.g. `timeout()` or `proxy()`, are not used by it.
+    pub fn transport<T>(&mut self, transport: T) -> &mut Self
+    where
+        T: Transport + 'static,
+    {
+        self.transport = Some(Arc::new(transport));
+        self
+    }
+
+    /// # Panics
+    /// Panics if the HTTP client can't be initialized, e.g. with an invalid proxy URL or header.
+    /// Use `try_build()` to get the error instead.
+    pub fn build(&self) -> Client {
+        self.try_build().expect("failed to build the HTTP client")
+    }
+
+    pub fn try_build(&self) -> Result<Client, Error> {
+        let transport = match &self.transport {
+            Some(transport) => transport.clone(),
+            None => Arc::new(self.blocking_http()?),
+        };
+        Ok(Client {
+            token: self.token.clone(),
+            core: self.core(),
+            transport,
+        })
+    }
+
+    /// Build with the token saved in `store`, ignoring `token()`.   
+    /// Returns `ErrorKind::MissingToken` if the store is empty.
+    pub fn build_with_store<S>(&self, store: S) -> Result<Client, Error>
+    where
+        S: TokenStore + 'static,
+    {
+        let token = store.load()?.ok_or(ErrorKind::MissingToken)?;
+        let mut client = self.try_build()?;
+        client.token = token.access_token;
+        client.core.store = Some(Arc::new(store));
+        Ok(client)
+    }
+}
@@ -0,0 +1,217 @@
+use reqwest::header::{HeaderMap, RETRY_AFTER};
+use reqwest::StatusCode;
+use serde::{Deserialize, Serialize};
+use std::error::Error as StdError;
+use std::fmt;
+use std::time::Duration;
+
+/// An element of the `errors` array which the Annict API responds with.
+
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct ApiError {
+    #[serde(rename = "type")]
+    pub kind: String,
+    pub short_message: String,
+    pub message: String,
+}
+
+/// The error body of the OAuth endpoints.
+
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct OAuthError {
+    pub error: String,
+    pub error_description: Option<String>,
+}
+
+/// What went wrong. Returned by `Error::kind()`.
+
+#[derive(Debug)]
+pub enum ErrorKind {
+    /// Failed to connect, or the connection was lost.
+    Network,
+    /// The request timed out, or `LoopbackLogin` got no redirect in time.
+    Timeout,
+    /// 401 Unauthorized. The access token is invalid, expired or revoked.
+    Unauthorized(Vec<ApiError>),
+    /// 404 Not Found.
+    NotFound(Vec<ApiError>),
+    /// 429 Too Many Requests.
+    RateLimited {
+        retry_after: Option<Duration>,
+        errors: Vec<ApiError>,
+    },
+    /// Any other non-success status.
+    Status {
+        status: StatusCode,
+        errors: Vec<ApiError>,
+    },
+    /// The response body couldn't be decoded.
+    Decode,
+    /// The request couldn't be made from the given parameters.
+    InvalidParameter(String),
+    /// The OAuth endpoint rejected the request, e.g. with `invalid_grant`.
+    OAuth(OAuthError),
+    /// The `state` of an authorization callback didn't match the one sent.
+    StateMismatch,
+    /// A local I/O operation failed.
+    Io,
+    /// The token store has no token.
+    MissingToken,
+}
+
+/// The error type of annis.
+
+#[derive(Debug)]
+pub struct Error {
+    kind: ErrorKind,
+    source: Option<Box<dyn StdError + Send + Sync>>,
+}
+
+#[derive(Deserialize)]
+struct ErrorBody {
+    errors: Vec<ApiError>,
+}
+
+impl Error {
+    pub fn kind(&self) -> &ErrorKind {
+        &self.kind
+    }
+
+    /// The HTTP status, if the server responded with an error status.
+    pub fn status(&self) -> Option<StatusCode> {
+        match self.kind {
+            ErrorKind::Unauthorized(_) => Some(StatusCode::UNAUTHORIZED),
+            ErrorKind::NotFound(_) => Some(StatusCode::NOT_FOUND),
+            ErrorKind::RateLimited { .. } => Some(StatusCode::TOO_MANY_REQUESTS),
+            ErrorKind::Status { status, .. } => Some(status),
+            _ => None,
+        }
+    }
+
+    /// The `errors` array of the response, if the server responded with an error status.
+    pub fn api_errors(&self) -> &[ApiError] {
+        match &self.kind {
+            ErrorKind::Unauthorized(errors)
+            | ErrorKind::NotFound(errors)
+            | ErrorKind::RateLimited { errors, .. }
+            | ErrorKind::Status { errors, .. } => errors,
+            _ => &[],
+        }
+    }
+
+    /// Makes the error for a non-success response.
+    pub(crate) fn from_response(status: StatusCode, headers: &HeaderMap, body: &str) -> Error {
+        let errors = serde_json::from_str::<ErrorBody>(body)
+            .map(|body| body.errors)
+            .unwrap_or_default();
+        let kind = match status {
+            StatusCode::UNAUTHORIZED => ErrorKind::Unauthorized(errors),
+            StatusCode::NOT_FOUND => ErrorKind::NotFound(errors),
+            StatusCode::TOO_MANY_REQUESTS => ErrorKind::RateLimited {
+                retry_after: headers
+                    .get(RETRY_AFTER)
+                    .and_then(|v| v.to_str().ok())
+                    .and_then(|v| v.trim().parse().ok())
+                    .map(Duration::from_secs),
+                errors,
+            },
+            status => ErrorKind::Status { status, errors },
+        };
+        kind.into()
+    }
+
+    /// Makes the error for a non-success response of /oauth/token.
+    pub(crate) fn from_oauth_response(status: StatusCode, headers: &HeaderMap, body: &str) -> Error {
+        match serde_json::from_str::<OAuthError>(body) {
+            Ok(e) => ErrorKind::OAuth(e).into(),
+            Err(_) => Error::from_response(status, headers, body),
+        }
+    }
+}
+
+impl fmt::Display for ErrorKind {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            ErrorKind::Network => write!(f, "Network error"),
+            ErrorKind::Timeout => write!(f, "Request timed out"),
+            ErrorKind::Unauthorized(_) => write!(f, "Unauthorized access token"),
+            ErrorKind::NotFound(_) => write!(f, "Not found"),
+            ErrorKind::RateLimited { .. } => write!(f, "Rate limited"),
+            ErrorKind::Status { status, .. } => write!(f, "Server responded with {}", status),
+            ErrorKind::Decode => write!(f, "Failed to decode the response"),
+            ErrorKind::InvalidParameter(p) => write!(f, "Invalid parameter: {}", p),
+            ErrorKind::OAuth(e) => match &e.error_description {
+                Some(description) => write!(f, "OAuth error {}: {}", e.error, description),
+                None => write!(f, "OAuth error {}", e.error),
+            },
+            ErrorKind::StateMismatch => write!(f, "OAuth state mismatch"),
+            ErrorKind::Io => write!(f, "I/O error"),
+            ErrorKind::MissingToken => write!(f, "No access token in the store"),
+        }
+    }
+}
+
+impl fmt::Display for Error {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        fmt::Display::fmt(&self.kind, f)?;
+        if let Some(e) = self.api_errors().first() {
+            write!(f, ": {}", e.message)?;
+        }
+        Ok(())
+    }
+}
+
+impl StdError for Error {
+    fn source(&self) -> Option<&(dyn StdError + 'static)> {
+        self.source
+            .as_ref()
+            .map(|e| e.as_ref() as &(dyn StdError + 'static))
+    }
+}
+
+impl From<ErrorKind> for Error {
+    fn from(kind: ErrorKind) -> Error {
+        Error { kind, source: None }
+    }
+}
+
+impl From<reqwest::Error> for Error {
+    fn from(error: reqwest::Error) -> Error {
+        let kind = if error.is_timeout() {
+            ErrorKind::Timeout
+        } else if error.is_decode() {
+            ErrorKind::Decode
+        } else if error.is_builder() {
+            ErrorKind::InvalidParameter(error.to_string())
+        } else if let Some(status) = error.status() {
+            ErrorKind::Status {
+                status,
+                errors: vec![],
+            }
+        } else {
+            ErrorKind::Network
+        };
+        Error {
+            kind,
+            source: Some(Box::new(error)),
+        }
+    }
+}
+
+impl From<serde_json::Error> for Error {
+    fn from(error: serde_json::Error) -> Error {
+        Error {
+            kind: ErrorKind::Decode,
+            source: Some(Box::new(error)),
+        }
+    }
+}
+
+impl From<std::io::Error> for Error {
+    fn from(error: std::io::Error) -> Error {
+        Error {
+            kind: ErrorKind::Io,
+            source: Some(Box::new(error)),
+        }
+    }
+}
@@ -0,0 +1,486 @@
+use crate::{
+    models, Activities, Casts, Characters, Endpoint, Episodes, Followers, Following, IsValid, Me,
+    MeFollowing_activities, MePrograms, MeWorks, Organizations, Paginate, People, Records, Reviews,
+    Series, Service, Staffs, Users, Works,
+};
+use serde::de::DeserializeOwned;
+use serde::Serialize;
+use std::fmt;
+use std::str::FromStr;
+
+macro_rules! field {
+    (
+        $(#[$attr:meta])*
+        $name:ident {
+            $($leaf:ident => $key:literal,)*
+        }
+        $(nested {
+            $($nested:ident($inner:ident) => $prefix:literal,)*
+        })?
+    ) => {
+        $(#[$attr])*
+        #[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+        pub enum $name {
+            $($leaf,)*
+            $($($nested($inner),)*)?
+        }
+
+        impl fmt::Display for $name {
+            fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+                match self {
+                    $($name::$leaf => f.write_str($key),)*
+                    $($($name::$nested(field) => write!(f, concat!($prefix, ".{}"), field),)*)?
+                }
+            }
+        }
+
+        impl From<$name> for String {
+            fn from(field: $name) -> String {
+                field.to_string()
+            }
+        }
+    };
+}
+
+field!(
+    /// A field of a work, selected with `Service::fields()`.
+    /// `Images` selects the whole `images` object.
+
+    WorkField {
+        Id => "id",
+        Title => "title",
+        TitleKana => "title_kana",
+        Media => "media",
+        MediaText => "media_text",
+        SeasonName => "season_name",
+        SeasonNameText => "season_name_text",
+        ReleasedOn => "released_on",
+        ReleasedOnAbout => "released_on_about",
+        OfficialSiteUrl => "official_site_url",
+        WikipediaUrl => "wikipedia_url",
+        TwitterUsername => "twitter_username",
+        TwitterHashtag => "twitter_hashtag",
+        SyobocalTid => "syobocal_tid",
+        MalAnimeId => "mal_anime_id",
+        Images => "images",
+        EpisodesCount => "episodes_count",
+        WatchersCount => "watchers_count",
+        ReviewsCount => "reviews_count",
+        NoEpisodes => "no_episodes",
+    }
+);
+
+field!(
+    /// A field of an episode, selected with `Service::fields()`.
+    /// `Work(WorkField::Title)` selects `work.title`.
+
+    EpisodeField {
+        Id => "id",
+        Number => "number",
+        NumberText => "number_text",
+        SortNumber => "sort_number",
+        Title => "title",
+        RecordsCount => "records_count",
+        RecordCommentsCount => "record_comments_count",
+        PrevEpisode => "prev_episode",
+        NextEpisode => "next_episode",
+    }
+    nested {
+        Work(WorkField) => "work",
+    }
+);
+
+field!(
+    /// A field of a user, selected with `Service::fields()`.
+
+    UserField {
+        Id => "id",
+        Username => "username",
+        Name => "name",
+        Description => "description",
+        Url => "url",
+        AvatarUrl => "avatar_url",
+        BackgroundImageUrl => "background_image_url",
+        RecordsCount => "records_count",
+        FollowingsCount => "followings_count",
+        FollowersCount => "followers_count",
+        WannaWatchCount => "wanna_watch_count",
+        WatchingCount => "watching_count",
+        WatchedCount => "watched_count",
+        OnHoldCount => "on_hold_count",
+        StopWatchingCount => "stop_watching_count",
+        CreatedAt => "created_at",
+        Email => "email",
+        NotificationsCount => "notifications_count",
+    }
+);
+
+field!(
+    /// A field of a record, selected with `Service::fields()`.
+
+    RecordField {
+        Id => "id",
+        Comment => "comment",
+        Rating => "rating",
+        RatingState => "rating_state",
+        IsModified => "is_modified",
+        LikesCount => "likes_count",
+        CommentsCount => "comments_count",
+        CreatedAt => "created_at",
+    }
+    nested {
+        User(UserField) => "user",
+        Work(WorkField) => "work",
+        Episode(EpisodeField) => "episode",
+    }
+);
+
+field!(
+    /// A field of a review, selected with `Service::fields()`.
+
+    ReviewField {
+        Id => "id",
+        Title => "title",
+        Body => "body",
+        RatingAnimationState => "rating_animation_state",
+        RatingMusicState => "rating_music_state",
+        RatingStoryState => "rating_story_state",
+        RatingCharacterState => "rating_character_state",
+        RatingOverallState => "rating_overall_state",
+        LikesCount => "likes_count",
+        ImpressionsCount => "impressions_count",
+        ModifiedAt => "modified_at",
+        CreatedAt => "created_at",
+    }
+    nested {
+        User(UserField) => "user",
+        Work(WorkField) => "work",
+    }
+);
+
+field!(
+    /// A field of a broadcast program, selected with `Service::fields()`.
+    /// `Channel` selects the whole `channel` object.
+
+    ProgramField {
+        Id => "id",
+        StartedAt => "started_at",
+        IsRebroadcast => "is_rebroadcast",
+        Channel => "channel",
+    }
+    nested {
+        Work(WorkField) => "work",
+        Episode(EpisodeField) => "episode",
+    }
+);
+
+field!(
+    /// A field of an activity, selected with `Service::fields()`.
+    /// `MultipleRecords` and `Status` select the whole objects.
+
+    ActivityField {
+        Id => "id",
+        Action => "action",
+        CreatedAt => "created_at",
+        MultipleRecords => "multiple_records",
+        Status => "status",
+    }
+    nested {
+        User(UserField) => "user",
+        Work(WorkField) => "work",
+        Episode(EpisodeField) => "episode",
+        Record(RecordField) => "record",
+        Review(ReviewField) => "review",
+    }
+);
+
+field!(
+    /// A field of a person, selected with `Service::fields()`.
+    /// `Prefecture` selects the whole `prefecture` object.
+
+    PersonField {
+        Id => "id",
+        Name => "name",
+        NameKana => "name_kana",
+        NameEn => "name_en",
+        Nickname => "nickname",
+        NicknameEn => "nickname_en",
+        GenderText => "gender_text",
+        Url => "url",
+        UrlEn => "url_en",
+        WikipediaUrl => "wikipedia_url",
+        WikipediaUrlEn => "wikipedia_url_en",
+        TwitterUsername => "twitter_username",
+        TwitterUsernameEn => "twitter_username_en",
+        Birthday => "birthday",
+        BloodType => "blood_type",
+        Height => "height",
+        FavoritePeopleCount => "favorite_people_count",
+        CastsCount => "casts_count",
+        StaffsCount => "staffs_count",
+        Prefecture => "prefecture",
+    }
+);
+
+field!(
+    /// A field of an organization, selected with `Service::fields()`.
+
+    OrganizationField {
+        Id => "id",
+        Name => "name",
+        NameKana => "name_kana",
+        NameEn => "name_en",
+        Url => "url",
+        UrlEn => "url_en",
+        WikipediaUrl => "wikipedia_url",
+        WikipediaUrlEn => "wikipedia_url_en",
+        TwitterUsername => "twitter_username",
+        TwitterUsernameEn => "twitter_username_en",
+        FavoriteOrganizationsCount => "favorite_organizations_count",
+        StaffsCount => "staffs_count",
+    }
+);
+
+field!(
+    /// A field of a series, selected with `Service::fields()`.
+
+    SeriesField {
+        Id => "id",
+        Name => "name",
+        NameRo => "name_ro",
+        NameEn => "name_en",
+    }
+);
+
+field!(
+    /// A field of a character, selected with `Service::fields()`.
+
+    CharacterField {
+        Id => "id",
+        Name => "name",
+        NameKana => "name_kana",
+        NameEn => "name_en",
+        Nickname => "nickname",
+        NicknameEn => "nickname_en",
+        Birthday => "birthday",
+        BirthdayEn => "birthday_en",
+        Age => "age",
+        AgeEn => "age_en",
+        BloodType => "blood_type",
+        BloodTypeEn => "blood_type_en",
+        Height => "height",
+        HeightEn => "height_en",
+        Weight => "weight",
+        WeightEn => "weight_en",
+        Nationality => "nationality",
+        NationalityEn => "nationality_en",
+        Occupation => "occupation",
+        OccupationEn => "occupation_en",
+        Description => "description",
+        DescriptionEn => "description_en",
+        DescriptionSource => "description_source",
+        DescriptionSourceEn => "description_source_en",
+        FavoriteCharactersCount => "favorite_characters_count",
+    }
+    nested {
+        Series(SeriesField) => "series",
+    }
+);
+
+field!(
+    /// A field of a cast, selected with `Service::fields()`.
+
+    CastField {
+        Id => "id",
+        Name => "name",
+        NameEn => "name_en",
+        SortNumber => "sort_number",
+    }
+    nested {
+        Work(WorkField) => "work",
+        Character(CharacterField) => "character",
+        Person(PersonField) => "person",
+    }
+);
+
+field!(
+    /// A field of a staff, selected with `Service::fields()`.
+
+    StaffField {
+        Id => "id",
+        Name => "name",
+        NameEn => "name_en",
+        RoleText => "role_text",
+        RoleOther => "role_other",
+        RoleOtherEn => "role_other_en",
+        SortNumber => "sort_number",
+    }
+    nested {
+        Work(WorkField) => "work",
+        Person(PersonField) => "person",
+        Organization(OrganizationField) => "organization",
+    }
+);
+
+/// A parameter type of the endpoints which accept `fields`.
+/// Ties the endpoint to its field type and to the response decoded when only some fields are selected.
+
+pub trait Select: Endpoint {
+    type Field: fmt::Display;
+    type Partial: DeserializeOwned;
+    fn fields_key() -> Self;
+}
+
+macro_rules! select {
+    ($param:ident, $field:ident, $partial:ty) => {
+        impl Select for $param {
+            type Field = $field;
+            type Partial = $partial;
+
+            fn fields_key() -> Self {
+                $param::fields
+            }
+        }
+
+        impl From<&'static str> for Partial<$param> {
+            fn from(p: &'static str) -> Self {
+                Partial(p.into())
+            }
+        }
+
+        impl From<String> for Partial<$param> {
+            fn from(p: String) -> Self {
+                Partial(p.into())
+            }
+        }
+    };
+}
+
+select!(Works, WorkField, models::PartialWorksResponse);
+select!(MeWorks, WorkField, models::PartialWorksResponse);
+select!(Episodes, EpisodeField, models::PartialEpisodesResponse);
+select!(Records, RecordField, models::PartialRecordsResponse);
+select!(Reviews, ReviewField, models::PartialReviewsResponse);
+select!(Users, UserField, models::PartialUsersResponse);
+select!(Following, UserField, models::PartialUsersResponse);
+select!(Followers, UserField, models::PartialUsersResponse);
+select!(Me, UserField, models::PartialUser);
+select!(MePrograms, ProgramField, models::PartialProgramsResponse);
+select!(Activities, ActivityField, models::PartialActivitiesResponse);
+select!(
+    MeFollowing_activities,
+    ActivityField,
+    models::PartialActivitiesResponse
+);
+select!(People, PersonField, models::PartialPeopleResponse);
+select!(
+    Organizations,
+    OrganizationField,
+    models::PartialOrganizationsResponse
+);
+select!(Series, SeriesField, models::PartialSeriesResponse);
+select!(
+    Characters,
+    CharacterField,
+    models::PartialCharactersResponse
+);
+select!(Casts, CastField, models::PartialCastsResponse);
+select!(Staffs, StaffField, models::PartialStaffsResponse);
+
+/// A parameter of a Service made by `Service::fields()`.
+/// Takes the same keys as `P`, also as strings, and is fetched as `P::Partial`.
+
+#[derive(Debug, Clone, PartialEq, Serialize)]
+#[serde(transparent)]
+pub struct Partial<P>(pub P);
+
+impl<P> From<P> for Partial<P> {
+    fn from(p: P) -> Self {
+        Partial(p)
+    }
+}
+
+impl<P: Into<String>> From<Partial<P>> for String {
+    fn from(p: Partial<P>) -> String {
+        p.0.into()
+    }
+}
+
+impl<P: FromStr> FromStr for Partial<P> {
+    type Err = P::Err;
+
+    fn from_str(p: &str) -> Result<Self, Self::Err> {
+        p.parse().map(Partial)
+    }
+}
+
+impl<P: IsValid> IsValid for Partial<P> {
+    fn is_valid(&self) -> bool {
+        self.0.is_valid()
+    }
+}
+
+impl<P: Select> Endpoint for Partial<P> {
+    type Response = P::Partial;
+}
+
+impl<P: Select + Paginate> Paginate for Partial<P> {
+    fn page_key() -> Self {
+        Partial(P::page_key())
+    }
+}
+
+impl<P> Service<P>
+where
+    P: Select + Into<String> + PartialEq,
+{
+    /// Request only `fields` of the resources, e.g. `work.title` for `EpisodeField::Work(WorkField::Title)`.
+    /// The response is decoded as `P::Partial`, whose fields are all `Option`.
+    ///
+    /// Examples
+    /// ========
+    /// ```rust
+    /// # #[cfg(feature = "blocking")]
+    /// # {
+    /// # use annis::{Client, Error, WorkField, Works};
+    /// #
+    /// # fn run() -> Result<(), Error> {
+    /// let client = Client::set_token("annict_access_token");
+    ///
+    /// let works = annis::works()
+    ///     .params(vec![(Works::filter_title, "lain")])
+    ///     .fields(vec![WorkField::Title, WorkField::Images]);
+    ///
+    /// for work in client.fetch(works)?.works {
+    ///     println!("{:?}", work.title);
+    /// }
+    /// # Ok(())
+    /// # }
+    /// # }
+    /// ```
+
+    pub fn fields<I>(self, fields: I) -> Service<Partial<P>>
+    where
+        I: IntoIterator<Item = P::Field>,
+    {
+        let fields = fields
+            .into_iter()
+            .map(|field| field.to_string())
+            .collect::<Vec<_>>()
+            .join(",");
+        let mut params: Vec<(Partial<P>, String)> = self
+            .params
+            .unwrap_or_default()
+            .into_iter()
+            .filter(|(k, _)| *k != P::fields_key())
+            .map(|(k, v)| (Partial(k), v))
+            .collect();
+        params.push((Partial(P::fields_key()), fields));
+        Service {
+            method: self.method,
+            path: self.path,
+            params: Some(params),
+            encoding: self.encoding,
+        }
+    }
+}
@@ -1,4 +1,7 @@
 #![doc(html_root_url = "https://!docs.rs/annis/0.0.4")]
+#![allow(clippy::empty_line_after_doc_comments)]
+// Without a client, the request logic shared by them is unused.
+#![cfg_attr(not(any(feature = "blocking", feature = "async")), allow(dead_code))]
 
 //! annis
 //! =====
@@ -10,11 +13,13 @@
 //! --------------------
 //! Example: Request to /v1/works
 //! ```no_run
+//! # #[cfg(feature = "blocking")]
+//! # {
 //! # extern crate annis;
 //! # use annis::{Client, Works, Error, Value};
 //! # use std::env;
 //! #
-//! # fn main() -> Result<(), Error>{
+//! # fn run() -> Result<(), Error>{
 //! #
 //! let client = Client::set_token("annict_access_token");
 //!
@@ -27,33 +32,255 @@
 //! #
 //! #   Ok(())  
 //! # }
+//! # }
+//! ```
+//!
+//! Features
+//! --------------------
+//! - `blocking`: `Client`, and the functions of `OAuth` and `AccessToken` which send requests.
+//! - `async`: the `nonblocking` module, and the `_async` functions of `AccessToken`.
+//! - `native-tls`: HTTPS with the TLS library of the system, e.g. OpenSSL.
+//! - `rustls-tls`: HTTPS with rustls instead, for a build without OpenSSL.
+//!
+//! `blocking`, `async` and `native-tls` are enabled by default.
+//! ```toml
+//! [dependencies]
+//! annis = { version = "0.0.6", default-features = false, features = ["async", "rustls-tls"] }
 //! ```
 
 
 use std::fmt;
+use std::str::FromStr;
 
-use reqwest;
+use reqwest::header::{HeaderMap, HeaderName, HeaderValue};
+use serde::de::DeserializeOwned;
 use serde::{Serialize, Deserialize};
-use serde_json;
-use failure;
 
+mod api;
 mod auth;
+#[cfg(any(feature = "blocking", feature = "async"))]
+mod builder;
+mod cache;
+#[cfg(feature = "blocking")]
 mod client;
+mod error;
+mod fields;
+#[cfg(feature = "blocking")]
+mod loopback;
+pub mod models;
+#[cfg(feature = "async")]
 pub mod nonblocking;
-
+mod pagination;
+mod rating;
+mod rate_limit;
+mod retry;
+mod review;
+mod scope;
+mod season;
+mod status;
+mod store;
+mod transport;
+
+pub use crate::api::Api;
 pub use crate::auth::*;
-pub use crate::client::Client;
+pub use crate::cache::{CacheStore, CachedResponse, DiskCache, MemoryCache, ResponseCache};
+#[cfg(any(feature = "blocking", feature = "async"))]
+pub use crate::builder::ClientBuilder;
+#[cfg(feature = "blocking")]
+pub use crate::client::{Client, Paginator};
+pub use crate::error::*;
+pub use crate::fields::{
+    ActivityField, CastField, CharacterField, EpisodeField, OrganizationField, Partial,
+    PersonField, ProgramField, RecordField, ReviewField, Select, SeriesField, StaffField,
+    UserField, WorkField,
+};
+#[cfg(feature = "blocking")]
+pub use crate::loopback::LoopbackLogin;
+pub use crate::rate_limit::{RateBudget, RateLimiter};
+pub use crate::rating::RatingState;
+pub use crate::retry::RetryPolicy;
+pub use crate::review::ReviewDraft;
+pub use crate::scope::{Scope, Scopes};
+pub use crate::season::{Quarter, Season, SeasonRange};
+pub use crate::status::WatchStatus;
+pub use crate::store::{FileTokenStore, MemoryTokenStore, TokenStore};
+#[cfg(feature = "async")]
+pub use crate::transport::AsyncTransport;
+pub use crate::transport::{HttpRequest, HttpResponse, Transport};
 pub use serde_json::Value;
 
-/// A Service to make request to endpoint.   
+/// Compiles the examples of the README files, which use both clients.
+
+#[cfg(all(doctest, feature = "blocking", feature = "async"))]
+#[doc = include_str!("../README.md")]
+#[doc = include_str!("../README-ja.md")]
+struct ReadmeDoctests;
+
+/// The default base URL of the Annict API.
+
+pub(crate) const API_BASE_URL: &str = "https://api.annict.com";
+
+/// The default base URL of the Annict web site, which serves the authorize page.
+
+pub(crate) const SITE_BASE_URL: &str = "https://annict.com";
+
+/// Joins a base URL and a path of Service.
+
+pub(crate) fn join_url(base_url: &str, path: &str) -> String {
+    format!("{}{}", base_url.trim_end_matches('/'), path)
+}
+
+/// The `User-Agent` which clients send unless ClientBuilder sets another.
+
+pub(crate) const USER_AGENT: &str = concat!("annis/", env!("CARGO_PKG_VERSION"));
+
+/// How a client sends its access token. Set with `ClientBuilder::auth_method()`.
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum AuthMethod {
+    /// `Authorization: Bearer <token>` header. The default.
+    #[default]
+    Bearer,
+    /// `access_token` query parameter, which shows up in URLs and their logs.
+    QueryParameter,
+}
+
+/// A proxy set on ClientBuilder, with the schemes it is used for.
+/// Its Debug output leaves out the credentials of the URL.
+
+#[derive(Clone)]
+pub(crate) enum ProxyConfig {
+    All(String),
+    Http(String),
+    Https(String),
+}
+
+impl ProxyConfig {
+    pub(crate) fn build(&self) -> Result<reqwest::Proxy, Error> {
+        let proxy = match self {
+            ProxyConfig::All(url) => reqwest::Proxy::all(url.as_str()),
+            ProxyConfig::Http(url) => reqwest::Proxy::http(url.as_str()),
+            ProxyConfig::Https(url) => reqwest::Proxy::https(url.as_str()),
+        };
+        proxy.map_err(|_| ErrorKind::InvalidParameter(format!("proxy {:?}", self)).into())
+    }
+}
+
+impl fmt::Debug for ProxyConfig {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        let (name, url) = match self {
+            ProxyConfig::All(url) => ("All", url),
+            ProxyConfig::Http(url) => ("Http", url),
+            ProxyConfig::Https(url) => ("Https", url),
+        };
+        f.debug_tuple(name).field(&redact_userinfo(url)).finish()
+    }
+}
+
+/// Replaces `user:password` in the authority of a URL, which may not be valid, with `<redacted>`.
+
+fn redact_userinfo(url: &str) -> String {
+    let start = url.find("://").map_or(0, |i| i + 3);
+    let end = url[start..]
+        .find(['/', '?', '#'])
+        .map_or(url.len(), |i| start + i);
+    match url[start..end].rfind('@') {
+        Some(at) => format!("{}<redacted>{}", &url[..start], &url[start + at..]),
+        None => url.to_string(),
+    }
+}
+
+/// Makes the default headers of a client from the pairs set on ClientBuilder.
+
+pub(crate) fn header_map(headers: &[(String, String)]) -> Result<HeaderMap, Error> {
+    let mut map = HeaderMap::new();
+    for (name, value) in headers {
+        let invalid = || ErrorKind::InvalidParameter(format!("header {}: {}", name, value));
+        map.append(
+            HeaderName::from_bytes(name.as_bytes()).map_err(|_| invalid())?,
+            HeaderValue::from_str(value).map_err(|_| invalid())?,
+        );
+    }
+    Ok(map)
+}
+
+/// Makes an unguessable value for the OAuth `state` parameter,
+/// 16 bytes from the random source of the OS in hex.
+/// Panics if the OS has no random source.
+///
+/// Examples
+/// ========
+/// ```rust
+/// # use annis::OAuth;
+/// let state = annis::random_state();
+///
+/// // -> Keep `state` in the user's session.
+/// let url = OAuth::client_id("client_id")
+///     .authorize_url()
+///     .redirect_uri("https://example.com/callback")
+///     .state(state.as_str())
+///     .build();
+/// ```
+
+pub fn random_state() -> String {
+    let mut bytes = [0u8; 16];
+    getrandom::getrandom(&mut bytes).expect("the random source of the OS is unavailable");
+    bytes.iter().map(|b| format!("{:02x}", b)).collect()
+}
+
+/// Compares in a time which doesn't depend on where `a` and `b` differ.
+fn constant_time_eq(a: &[u8], b: &[u8]) -> bool {
+    a.len() == b.len() && a.iter().zip(b).fold(0, |diff, (x, y)| diff | (x ^ y)) == 0
+}
+
+/// Checks a redirect from the authorize page and returns its `code`.   
+/// `callback_url` may be a full URL or a path with the query, and `state`
+/// is the one sent with `AuthorizeUrl`.
 ///
+/// Examples
+/// ========
+/// ```rust
+/// # use annis::Error;
+/// # fn run(state: &str) -> Result<(), Error> {
+/// let code = annis::verify_callback("/callback?code=issued_code&state=abc", state)?;
+/// # Ok(())
+/// # }
+/// ```
+
+pub fn verify_callback(callback_url: &str, state: &str) -> Result<String, Error> {
+    let url = reqwest::Url::parse("http://127.0.0.1/")
+        .and_then(|base| base.join(callback_url))
+        .map_err(|e| ErrorKind::InvalidParameter(e.to_string()))?;
+    let param = |key: &str| {
+        url.query_pairs()
+            .find(|(k, _)| k == key)
+            .map(|(_, v)| v.into_owned())
+    };
+    // Annict sends `state` back with errors too, so a forged error is a mismatch.
+    let matches = param("state").is_some_and(|p| constant_time_eq(p.as_bytes(), state.as_bytes()));
+    if !matches {
+        return Err(ErrorKind::StateMismatch.into());
+    }
+    if let Some(error) = param("error") {
+        return Err(ErrorKind::OAuth(OAuthError {
+            error,
+            error_description: param("error_description"),
+        })
+        .into());
+    }
+    param("code").ok_or_else(|| ErrorKind::InvalidParameter("code is missing".into()).into())
+}
+
+/// A Service to make request to endpoint.   
+/// `path` is relative to the base URL of the client which calls it.
 ///
 
-#[derive(Debug)]
+#[derive(Debug, Clone)]
 pub struct Service<P: Into<String> + std::cmp::PartialEq> {
     pub method: reqwest::Method,
-    pub url: String,
+    pub path: String,
     pub params: Option<Vec<(P, String)>>,
+    pub encoding: Encoding,
 }
 
 impl<P: Into<String> + std::cmp::PartialEq + IsValid> Service<P> {
@@ -75,6 +302,69 @@ impl<P: Into<String> + std::cmp::PartialEq + IsValid> Service<P> {
             ..self
         }
     }
+
+    /// Add parameters with string keys, returning `ErrorKind::InvalidParameter`
+    /// for a key which the endpoint doesn't accept, instead of dropping it like `params()`.
+    ///
+    /// Examples
+    /// ========
+    /// ```rust
+    /// # use annis::Error;
+    /// # fn main() -> Result<(), Error> {
+    /// let works = annis::works().try_params(vec![("filter_title", "lain"), ("fields", "title")])?;
+    ///
+    /// assert!(annis::works().try_params(vec![("filter_titel", "lain")]).is_err());
+    /// # Ok(())
+    /// # }
+    /// ```
+
+    pub fn try_params<K, V>(self, params: Vec<(K, V)>) -> Result<Service<P>, Error>
+    where
+        P: FromStr<Err = Error>,
+        K: AsRef<str>,
+        V: Into<String>,
+    {
+        let params = params
+            .into_iter()
+            .map(|(k, v)| Ok((k.as_ref().parse()?, v.into())))
+            .collect::<Result<Vec<(P, String)>, Error>>()?;
+        Ok(self.params(params))
+    }
+
+    /// Send the parameters with `encoding` instead of the default of the method.
+    pub fn encoding(self, encoding: Encoding) -> Service<P> {
+        Service { encoding, ..self }
+    }
+}
+
+impl<P: Into<String> + std::cmp::PartialEq + Paginate + Clone> Service<P> {
+    /// The value of the `page` parameter, or 1 if it isn't set.
+    pub(crate) fn page(&self) -> u64 {
+        self.params
+            .iter()
+            .flatten()
+            .find(|(k, _)| *k == P::page_key())
+            .and_then(|(_, v)| v.parse().ok())
+            .unwrap_or(1)
+    }
+
+    /// A copy of this Service which requests the given page.
+    pub(crate) fn with_page(&self, page: u64) -> Service<P> {
+        let mut params: Vec<(P, String)> = self
+            .params
+            .iter()
+            .flatten()
+            .filter(|(k, _)| *k != P::page_key())
+            .cloned()
+            .collect();
+        params.push((P::page_key(), page.to_string()));
+        Service {
+            method: self.method.clone(),
+            path: self.path.clone(),
+            params: Some(params),
+            encoding: self.encoding,
+        }
+    }
 }
 
 /// A type of argument for me_records().
@@ -84,29 +374,104 @@ pub enum Method {
     DELETE,
 }
 
+/// How a Service sends its parameters.   
+/// GET and DELETE use the query string, and POST and PATCH a form body, unless `Service::encoding()` sets another.
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Encoding {
+    /// `?key=value` in the URL.
+    Query,
+    /// An `application/x-www-form-urlencoded` body.
+    Form,
+    /// An `application/json` body of string values.
+    Json,
+}
+
+impl Encoding {
+    pub(crate) fn for_method(method: &reqwest::Method) -> Encoding {
+        match *method {
+            reqwest::Method::POST | reqwest::Method::PATCH | reqwest::Method::PUT => Encoding::Form,
+            _ => Encoding::Query,
+        }
+    }
+}
+
+/// A value of the `sort_*` parameters, e.g. `(Works::sort_season, Order::Desc)`.
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Order {
+    Asc,
+    Desc,
+}
+
+impl From<Order> for String {
+    fn from(order: Order) -> String {
+        match order {
+            Order::Asc => "asc".to_string(),
+            Order::Desc => "desc".to_string(),
+        }
+    }
+}
+
+/// Makes the JSON body of `Encoding::Json` from the parameters of a Service.
+
+pub(crate) fn json_body<P: Serialize>(params: &[(P, String)]) -> serde_json::Map<String, Value> {
+    params
+        .iter()
+        .filter_map(|(k, v)| match serde_json::to_value(k) {
+            Ok(Value::String(k)) => Some((k, Value::String(v.clone()))),
+            _ => None,
+        })
+        .collect()
+}
+
 fn request<R: IsValid + Into<String> + std::cmp::PartialEq>(
     method: reqwest::Method,
-    url: String,
+    path: String,
 ) -> Service<R> {
     Service {
-        method: method,
-        url: url,
+        encoding: Encoding::for_method(&method),
+        method,
+        path,
         params: None,
     }
 }
 
-/// Request to /v1/records   
-/// .params() assepts `Records` enum.
+/// Request to /v1/reviews   
+/// .params() assepts `Reviews` enum.
+///
+/// Examples
+/// ========
+/// ```rust
+/// # #[cfg(feature = "blocking")]
+/// # {
+/// # use annis::{Client, Error, Reviews};
+/// #
+/// # fn run() -> Result<(), Error> {
+/// let client = Client::set_token("annict_access_token");
+///
+/// let reviews = annis::reviews().params(vec![
+///     (Reviews::filter_work_id, "5681"),
+///     (Reviews::sort_likes_count, "desc"),
+/// ]);
+///
+/// for review in client.fetch(reviews)?.reviews {
+///     println!("{:?} {:?}", review.title, review.likes_count);
+/// }
+/// # Ok(())
+/// # }
+/// # }
+/// ```
 
 pub fn reviews() -> Service<Reviews> {
     request(
         reqwest::Method::GET,
-        "https://api.annict.com/v1/records".to_string(),
+        "/v1/reviews".to_string(),
     )
 }
 
-/// used by records() function   
-/// /v1/records assepts parameters.
+/// used by reviews() function   
+/// /v1/reviews assepts parameters.
 
 #[allow(non_camel_case_types)]
 #[derive(Debug, Serialize, Deserialize, Clone, PartialEq)]
@@ -135,13 +500,21 @@ impl From<Reviews> for String {
 
 impl From<&'static str> for Reviews {
     fn from(p: &'static str) -> Self {
-        serde_json::from_str(p).unwrap_or(Reviews::Invalid)
+        p.parse().unwrap_or(Reviews::Invalid)
     }
 }
 
 impl From<String> for Reviews {
     fn from(p: String) -> Self {
-        serde_json::from_str(p.as_str()).unwrap_or(Reviews::Invalid)
+        p.parse().unwrap_or(Reviews::Invalid)
+    }
+}
+
+impl FromStr for Reviews {
+    type Err = Error;
+
+    fn from_str(p: &str) -> Result<Self, Error> {
+        parse_param(p)
     }
 }
 
@@ -151,13 +524,108 @@ impl fmt::Display for Reviews {
     }
 }
 
+/// used by OAuth::info() function   
+/// /oauth/token/info assepts no parameters.
+
+#[allow(non_camel_case_types)]
+#[derive(Debug, Serialize, Deserialize, Clone, PartialEq)]
+pub enum Info {
+    Invalid,
+}
+
+impl IsValid for Info {
+    fn is_valid(&self) -> bool {
+        *self != Info::Invalid
+    }
+}
+
+impl From<Info> for String {
+    fn from(p: Info) -> String {
+        serde_json::to_string(&p).unwrap_or(String::from("invalid parameter"))
+    }
+}
+
+impl From<&'static str> for Info {
+    fn from(p: &'static str) -> Self {
+        p.parse().unwrap_or(Info::Invalid)
+    }
+}
+
+impl From<String> for Info {
+    fn from(p: String) -> Self {
+        p.parse().unwrap_or(Info::Invalid)
+    }
+}
+
+impl FromStr for Info {
+    type Err = Error;
+
+    fn from_str(p: &str) -> Result<Self, Error> {
+        parse_param(p)
+    }
+}
+
+impl fmt::Display for Info {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "{:?}", self)
+    }
+}
+
+/// used by OAuth::revoke() function   
+/// /oauth/revoke assepts parameters.
+
+#[allow(non_camel_case_types)]
+#[derive(Debug, Serialize, Deserialize, Clone, PartialEq)]
+pub enum Revoke {
+    token,
+    Invalid,
+}
+
+impl IsValid for Revoke {
+    fn is_valid(&self) -> bool {
+        *self != Revoke::Invalid
+    }
+}
+
+impl From<Revoke> for String {
+    fn from(p: Revoke) -> String {
+        serde_json::to_string(&p).unwrap_or(String::from("invalid parameter"))
+    }
+}
+
+impl From<&'static str> for Revoke {
+    fn from(p: &'static str) -> Self {
+        p.parse().unwrap_or(Revoke::Invalid)
+    }
+}
+
+impl From<String> for Revoke {
+    fn from(p: String) -> Self {
+        p.parse().unwrap_or(Revoke::Invalid)
+    }
+}
+
+impl FromStr for Revoke {
+    type Err = Error;
+
+    fn from_str(p: &str) -> Result<Self, Error> {
+        parse_param(p)
+    }
+}
+
+impl fmt::Display for Revoke {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "{:?}", self)
+    }
+}
+
 /// Request to /v1/users   
 /// .params() assepts `Users` enum.
 
 pub fn users() -> Service<Users> {
     request(
         reqwest::Method::GET,
-        "https://api.annict.com/v1/users".to_string(),
+        "/v1/users".to_string(),
     )
 }
 
@@ -190,13 +658,21 @@ impl From<Users> for String {
 
 impl From<&'static str> for Users {
     fn from(p: &'static str) -> Self {
-        serde_json::from_str(p).unwrap_or(Users::Invalid)
+        p.parse().unwrap_or(Users::Invalid)
     }
 }
 
 impl From<String> for Users {
     fn from(p: String) -> Self {
-        serde_json::from_str(p.as_str()).unwrap_or(Users::Invalid)
+        p.parse().unwrap_or(Users::Invalid)
+    }
+}
+
+impl FromStr for Users {
+    type Err = Error;
+
+    fn from_str(p: &str) -> Result<Self, Error> {
+        parse_param(p)
     }
 }
 
@@ -212,7 +688,7 @@ impl fmt::Display for Users {
 pub fn following() -> Service<Following> {
     request(
         reqwest::Method::GET,
-        "https://api.annict.com/v1/following".to_string(),
+        "/v1/following".to_string(),
     )
 }
 
@@ -245,13 +721,21 @@ impl From<Following> for String {
 
 impl From<&'static str> for Following {
     fn from(p: &'static str) -> Self {
-        serde_json::from_str(p).unwrap_or(Following::Invalid)
+        p.parse().unwrap_or(Following::Invalid)
     }
 }
 
 impl From<String> for Following {
     fn from(p: String) -> Self {
-        serde_json::from_str(p.as_str()).unwrap_or(Following::Invalid)
+        p.parse().unwrap_or(Following::Invalid)
+    }
+}
+
+impl FromStr for Following {
+    type Err = Error;
+
+    fn from_str(p: &str) -> Result<Self, Error> {
+        parse_param(p)
     }
 }
 
@@ -267,7 +751,7 @@ impl fmt::Display for Following {
 pub fn followers() -> Service<Followers> {
     request(
         reqwest::Method::GET,
-        "https://api.annict.com/v1/followers".to_string(),
+        "/v1/followers".to_string(),
     )
 }
 
@@ -300,13 +784,21 @@ impl From<Followers> for String {
 
 impl From<&'static str> for Followers {
     fn from(p: &'static str) -> Self {
-        serde_json::from_str(p).unwrap_or(Followers::Invalid)
+        p.parse().unwrap_or(Followers::Invalid)
     }
 }
 
 impl From<String> for Followers {
     fn from(p: String) -> Self {
-        serde_json::from_str(p.as_str()).unwrap_or(Followers::Invalid)
+        p.parse().unwrap_or(Followers::Invalid)
+    }
+}
+
+impl FromStr for Followers {
+    type Err = Error;
+
+    fn from_str(p: &str) -> Result<Self, Error> {
+        parse_param(p)
     }
 }
 
@@ -321,7 +813,7 @@ impl fmt::Display for Followers {
 pub fn activities() -> Service<Activities> {
     request(
         reqwest::Method::GET,
-        "https://api.annict.com/v1/activities".to_string(),
+        "/v1/activities".to_string(),
     )
 }
 
@@ -354,13 +846,21 @@ impl From<Activities> for String {
 
 impl From<&'static str> for Activities {
     fn from(p: &'static str) -> Self {
-        serde_json::from_str(p).unwrap_or(Activities::Invalid)
+        p.parse().unwrap_or(Activities::Invalid)
     }
 }
 
 impl From<String> for Activities {
     fn from(p: String) -> Self {
-        serde_json::from_str(p.as_str()).unwrap_or(Activities::Invalid)
+        p.parse().unwrap_or(Activities::Invalid)
+    }
+}
+
+impl FromStr for Activities {
+    type Err = Error;
+
+    fn from_str(p: &str) -> Result<Self, Error> {
+        parse_param(p)
     }
 }
 
@@ -376,7 +876,7 @@ impl fmt::Display for Activities {
 pub fn me() -> Service<Me> {
     request(
         reqwest::Method::GET,
-        "https://api.annict.com/v1/me".to_string(),
+        "/v1/me".to_string(),
     )
 }
 
@@ -404,13 +904,21 @@ impl From<Me> for String {
 
 impl From<&'static str> for Me {
     fn from(p: &'static str) -> Self {
-        serde_json::from_str(p).unwrap_or(Me::Invalid)
+        p.parse().unwrap_or(Me::Invalid)
     }
 }
 
 impl From<String> for Me {
     fn from(p: String) -> Self {
-        serde_json::from_str(p.as_str()).unwrap_or(Me::Invalid)
+        p.parse().unwrap_or(Me::Invalid)
+    }
+}
+
+impl FromStr for Me {
+    type Err = Error;
+
+    fn from_str(p: &str) -> Result<Self, Error> {
+        parse_param(p)
     }
 }
 
@@ -421,22 +929,22 @@ impl fmt::Display for Me {
 }
 
 /// Request to /v1/me/reviews   
-/// .params() assepts `MeReviews` enum.
+/// .params() assepts `MeReviews` enum. `ReviewDraft` makes this Service with typed ratings.
 
 pub fn me_reviews(method: Method, id: usize) -> Service<MeReviews> {
     match method {
         Method::POST => request(
             reqwest::Method::POST,
-            "https://api.annict.com/v1/me/reviews".to_string(),
+            "/v1/me/reviews".to_string(),
         )
         .params(vec![(MeReviews::work_id, id.to_string())]),
         Method::PATCH => request(
             reqwest::Method::PATCH,
-            format!("https://api.annict.com/v1/me/reviews/{}", id),
+            format!("/v1/me/reviews/{}", id),
         ),
         Method::DELETE => request(
             reqwest::Method::DELETE,
-            format!("https://api.annict.com/v1/me/reviews/{}", id),
+            format!("/v1/me/reviews/{}", id),
         ),
     }
 }
@@ -474,13 +982,21 @@ impl From<MeReviews> for String {
 
 impl From<&'static str> for MeReviews {
     fn from(p: &'static str) -> Self {
-        serde_json::from_str(p).unwrap_or(MeReviews::Invalid)
+        p.parse().unwrap_or(MeReviews::Invalid)
     }
 }
 
 impl From<String> for MeReviews {
     fn from(p: String) -> Self {
-        serde_json::from_str(p.as_str()).unwrap_or(MeReviews::Invalid)
+        p.parse().unwrap_or(MeReviews::Invalid)
+    }
+}
+
+impl FromStr for MeReviews {
+    type Err = Error;
+
+    fn from_str(p: &str) -> Result<Self, Error> {
+        parse_param(p)
     }
 }
 
@@ -496,7 +1012,7 @@ impl fmt::Display for MeReviews {
 pub fn me_following_activities() -> Service<MeFollowing_activities> {
     request(
         reqwest::Method::GET,
-        "https://api.annict.com/v1/me/following_activities".to_string(),
+        "/v1/me/following_activities".to_string(),
     )
 }
 
@@ -529,13 +1045,21 @@ impl From<MeFollowing_activities> for String {
 
 impl From<&'static str> for MeFollowing_activities {
     fn from(p: &'static str) -> Self {
-        serde_json::from_str(p).unwrap_or(MeFollowing_activities::Invalid)
+        p.parse().unwrap_or(MeFollowing_activities::Invalid)
     }
 }
 
 impl From<String> for MeFollowing_activities {
     fn from(p: String) -> Self {
-        serde_json::from_str(p.as_str()).unwrap_or(MeFollowing_activities::Invalid)
+        p.parse().unwrap_or(MeFollowing_activities::Invalid)
+    }
+}
+
+impl FromStr for MeFollowing_activities {
+    type Err = Error;
+
+    fn from_str(p: &str) -> Result<Self, Error> {
+        parse_param(p)
     }
 }
 
@@ -551,6 +1075,8 @@ impl fmt::Display for MeFollowing_activities {
 /// Examples
 /// ========
 /// ```rust
+/// # #[cfg(feature = "blocking")]
+/// # {
 /// # use annis::{Client, Error};
 /// #
 /// # fn run() -> Result<(), Error> {
@@ -561,10 +1087,13 @@ impl fmt::Display for MeFollowing_activities {
 /// client.call(works)?;
 /// # Ok(())
 /// # }
+/// # }
 /// ```
 ///
 /// using enum code
 /// ```rust
+/// # #[cfg(feature = "blocking")]
+/// # {
 /// # use annis::{Client, Error};
 /// #
 /// # fn run() -> Result<(), Error> {
@@ -577,13 +1106,15 @@ impl fmt::Display for MeFollowing_activities {
 /// client.call(works)?;
 /// # Ok(())
 /// # }
+/// # }
 /// ```
 
 pub fn works() -> Service<Works> {
     Service {
         method: reqwest::Method::GET,
-        url: "https://api.annict.com/v1/works".to_string(),
+        path: "/v1/works".to_string(),
         params: None,
+        encoding: Encoding::Query,
     }
 }
 
@@ -593,6 +1124,8 @@ pub fn works() -> Service<Works> {
 /// Examples
 /// ========
 /// ```rust
+/// # #[cfg(feature = "blocking")]
+/// # {
 /// # use annis::{Client, Error};
 /// #
 /// # fn run() -> Result<(), Error> {
@@ -603,10 +1136,13 @@ pub fn works() -> Service<Works> {
 /// client.call(episodes)?;
 /// # Ok(())
 /// # }
+/// # }
 /// ```
 ///
 /// using enum code
 /// ```rust
+/// # #[cfg(feature = "blocking")]
+/// # {
 /// # use annis::{Client, Error};
 /// #
 /// # fn run() -> Result<(), Error> {
@@ -619,13 +1155,15 @@ pub fn works() -> Service<Works> {
 /// client.call(episodes)?;
 /// # Ok(())
 /// # }
+/// # }
 /// ```
 
 pub fn episodes() -> Service<Episodes> {
     Service {
         method: reqwest::Method::GET,
-        url: "https://api.annict.com/v1/episodes".to_string(),
+        path: "/v1/episodes".to_string(),
         params: None,
+        encoding: Encoding::Query,
     }
 }
 
@@ -635,6 +1173,8 @@ pub fn episodes() -> Service<Episodes> {
 /// Examples
 /// ========
 /// ```rust
+/// # #[cfg(feature = "blocking")]
+/// # {
 /// # use annis::{Client, Error};
 /// #
 /// # fn run() -> Result<(), Error> {
@@ -645,10 +1185,13 @@ pub fn episodes() -> Service<Episodes> {
 /// client.call(records)?;
 /// # Ok(())
 /// # }
+/// # }
 /// ```
 ///
 /// using enum code.
 /// ```rust
+/// # #[cfg(feature = "blocking")]
+/// # {
 /// # use annis::{Client, Error};
 /// #
 /// # fn run() -> Result<(), Error> {
@@ -661,13 +1204,15 @@ pub fn episodes() -> Service<Episodes> {
 /// client.call(records)?;
 /// # Ok(())
 /// # }
+/// # }
 /// ```
 
 pub fn records() -> Service<Records> {
     Service {
         method: reqwest::Method::GET,
-        url: "https://api.annict.com/v1/records".to_string(),
+        path: "/v1/records".to_string(),
         params: None,
+        encoding: Encoding::Query,
     }
 }
 
@@ -677,6 +1222,8 @@ pub fn records() -> Service<Records> {
 /// Examples
 /// ========
 /// ```rust
+/// # #[cfg(feature = "blocking")]
+/// # {
 /// # use annis::{Client, Error};
 /// #
 /// # fn run() -> Result<(), Error> {
@@ -687,10 +1234,13 @@ pub fn records() -> Service<Records> {
 /// client.call(statuses)?;
 /// # Ok(())
 /// # }
+/// # }
 /// ```
 ///
 /// using enum code.
 /// ```rust
+/// # #[cfg(feature = "blocking")]
+/// # {
 /// # use annis::{Client, Error};
 /// #
 /// # fn run() -> Result<(), Error> {
@@ -703,16 +1253,63 @@ pub fn records() -> Service<Records> {
 /// client.call(statuses)?;
 /// # Ok(())
 /// # }
+/// # }
+/// ```
+///
+/// using `WatchStatus`, or `me_statuses_set()`.
+/// ```rust
+/// # #[cfg(feature = "blocking")]
+/// # {
+/// # use annis::{Client, Error, MeStatuses, WatchStatus};
+/// #
+/// # fn run() -> Result<(), Error> {
+/// let client = Client::set_token("annict_access_token");
+///
+/// let statuses = annis::me_statuses().params(vec![
+///     (MeStatuses::work_id, "3994".to_string()),
+///     (MeStatuses::kind, WatchStatus::Watched.into()),
+/// ]);
+///
+/// client.call(statuses)?;
+/// # Ok(())
+/// # }
+/// # }
 /// ```
 
 pub fn me_statuses() -> Service<MeStatuses> {
     Service {
         method: reqwest::Method::POST,
-        url: "https://api.annict.com/v1/me/statuses".to_string(),
+        path: "/v1/me/statuses".to_string(),
         params: None,
+        encoding: Encoding::Form,
     }
 }
 
+/// Request to /v1/me/statuses to set the status of a work.
+///
+/// Examples
+/// ========
+/// ```rust
+/// # #[cfg(feature = "blocking")]
+/// # {
+/// # use annis::{Client, Error, WatchStatus};
+/// #
+/// # fn run() -> Result<(), Error> {
+/// let client = Client::set_token("annict_access_token");
+///
+/// client.call(annis::me_statuses_set(3994, WatchStatus::Watching))?;
+/// # Ok(())
+/// # }
+/// # }
+/// ```
+
+pub fn me_statuses_set(work_id: usize, status: WatchStatus) -> Service<MeStatuses> {
+    me_statuses().params(vec![
+        (MeStatuses::work_id, work_id.to_string()),
+        (MeStatuses::kind, status.into()),
+    ])
+}
+
 /// Request to /v1/me/records   
 /// .params() assepts `MeRecords` enum.
 ///
@@ -721,6 +1318,8 @@ pub fn me_statuses() -> Service<MeStatuses> {
 ///
 /// POST
 /// ```rust
+/// # #[cfg(feature = "blocking")]
+/// # {
 /// # use annis::{Client, Method, Error};
 /// #
 /// # fn post() -> Result<(), Error> {
@@ -731,9 +1330,12 @@ pub fn me_statuses() -> Service<MeStatuses> {
 /// client.call(records)?;
 /// # Ok(())
 /// # }
+/// # }
 /// ```
 /// PATCH
 /// ```rust
+/// # #[cfg(feature = "blocking")]
+/// # {
 /// # use annis::{Client, Method, Error};
 /// #
 /// # fn patch() -> Result<(), Error> {
@@ -744,12 +1346,15 @@ pub fn me_statuses() -> Service<MeStatuses> {
 /// client.call(records)?;
 /// # Ok(())
 /// # }
+/// # }
 /// ```
 ///
 /// using enum code
 /// ****************
 /// POST
 /// ```rust
+/// # #[cfg(feature = "blocking")]
+/// # {
 /// # use annis::{Client, Method, Error};
 /// #
 /// # fn post() -> Result<(), Error> {
@@ -762,9 +1367,12 @@ pub fn me_statuses() -> Service<MeStatuses> {
 /// client.call(records)?;
 /// # Ok(())
 /// # }
+/// # }
 /// ```
 /// PATCH
 /// ```rust
+/// # #[cfg(feature = "blocking")]
+/// # {
 /// # use annis::{Client, Method, Error};
 /// #
 /// # fn patch() -> Result<(), Error> {
@@ -777,32 +1385,34 @@ pub fn me_statuses() -> Service<MeStatuses> {
 /// client.call(records)?;
 /// # Ok(())
 /// # }
+/// # }
 /// ```
 
 pub fn me_records(method: Method, id: usize) -> Service<MeRecords> {
-    let (method, url, params): (reqwest::Method, String, Option<Vec<(MeRecords, String)>>) =
+    let (method, path, params): (reqwest::Method, String, Option<Vec<(MeRecords, String)>>) =
         match method {
             Method::POST => (
                 reqwest::Method::POST,
-                "https://api.annict.com/v1/me/records".to_string(),
+                "/v1/me/records".to_string(),
                 Some(vec![(MeRecords::episode_id, id.to_string())]),
             ),
             Method::PATCH => (
                 reqwest::Method::PATCH,
-                format!("https://api.annict.com/v1/me/records/{}", id),
+                format!("/v1/me/records/{}", id),
                 None,
             ),
             Method::DELETE => (
                 reqwest::Method::DELETE,
-                format!("https://api.annict.com/v1/me/records/{}", id),
+                format!("/v1/me/records/{}", id),
                 None,
             ),
         };
 
     Service {
-        method: method,
-        url: url,
-        params: params,
+        encoding: Encoding::for_method(&method),
+        method,
+        path,
+        params,
     }
 }
 
@@ -812,6 +1422,8 @@ pub fn me_records(method: Method, id: usize) -> Service<MeRecords> {
 /// Examples
 /// ========
 /// ```rust
+/// # #[cfg(feature = "blocking")]
+/// # {
 /// # use annis::{Client, Error};
 /// #
 /// # fn run() -> Result<(), Error> {
@@ -822,9 +1434,12 @@ pub fn me_records(method: Method, id: usize) -> Service<MeRecords> {
 /// client.call(me_works)?;
 /// # Ok(())
 /// # }
+/// # }
 /// ```
 /// using enum code
 /// ```rust
+/// # #[cfg(feature = "blocking")]
+/// # {
 /// # use annis::{Client, Error};
 /// #
 /// # fn run() -> Result<(), Error> {
@@ -837,13 +1452,15 @@ pub fn me_records(method: Method, id: usize) -> Service<MeRecords> {
 /// client.call(me_works)?;
 /// # Ok(())
 /// # }
+/// # }
 /// ```
 
 pub fn me_works() -> Service<MeWorks> {
     Service {
         method: reqwest::Method::GET,
-        url: "https://api.annict.com/v1/me/works".to_string(),
+        path: "/v1/me/works".to_string(),
         params: None,
+        encoding: Encoding::Query,
     }
 }
 
@@ -854,6 +1471,8 @@ pub fn me_works() -> Service<MeWorks> {
 /// ========
 ///
 /// ```rust
+/// # #[cfg(feature = "blocking")]
+/// # {
 /// # use annis::{Client, Error};
 /// #
 /// # fn run() -> Result<(), Error> {
@@ -864,9 +1483,12 @@ pub fn me_works() -> Service<MeWorks> {
 /// client.call(programs)?;
 /// # Ok(())
 /// # }
+/// # }
 /// ```
 /// using enum code
 /// ```rust
+/// # #[cfg(feature = "blocking")]
+/// # {
 /// # use annis::{Client, Error};
 /// #
 /// # fn run() -> Result<(), Error> {
@@ -879,13 +1501,15 @@ pub fn me_works() -> Service<MeWorks> {
 /// client.call(programs)?;
 /// # Ok(())
 /// # }
+/// # }
 /// ```
 
 pub fn me_programs() -> Service<MePrograms> {
     Service {
         method: reqwest::Method::GET,
-        url: "https://api.annict.com/v1/me/programs".to_string(),
+        path: "/v1/me/programs".to_string(),
         params: None,
+        encoding: Encoding::Query,
     }
 }
 
@@ -893,6 +1517,260 @@ pub trait IsValid {
     fn is_valid(&self) -> bool;
 }
 
+/// Parses the name of a parameter, e.g. `filter_title`.
+fn parse_param<P>(name: &str) -> Result<P, Error>
+where
+    P: DeserializeOwned + IsValid,
+{
+    serde_json::from_value::<P>(Value::String(name.to_string()))
+        .ok()
+        .filter(|p| p.is_valid())
+        .ok_or_else(|| ErrorKind::InvalidParameter(format!("unknown parameter {}", name)).into())
+}
+
+/// Ties a parameter type of `Service` to the typed response of its endpoint.   
+/// Used by `Client::fetch()`.
+
+pub trait Endpoint {
+    type Response: DeserializeOwned;
+}
+
+impl Endpoint for Works {
+    type Response = models::WorksResponse;
+}
+
+impl Endpoint for Episodes {
+    type Response = models::EpisodesResponse;
+}
+
+impl Endpoint for Records {
+    type Response = models::RecordsResponse;
+}
+
+impl Endpoint for Reviews {
+    type Response = models::ReviewsResponse;
+}
+
+impl Endpoint for Users {
+    type Response = models::UsersResponse;
+}
+
+impl Endpoint for Following {
+    type Response = models::UsersResponse;
+}
+
+impl Endpoint for Followers {
+    type Response = models::UsersResponse;
+}
+
+impl Endpoint for Activities {
+    type Response = models::ActivitiesResponse;
+}
+
+impl Endpoint for Me {
+    type Response = models::User;
+}
+
+/// `None` for DELETE, which responds with no content.
+impl Endpoint for MeReviews {
+    type Response = Option<models::Review>;
+}
+
+impl Endpoint for MeFollowing_activities {
+    type Response = models::ActivitiesResponse;
+}
+
+/// /v1/me/statuses responds with no content.
+impl Endpoint for MeStatuses {
+    type Response = ();
+}
+
+/// `None` for DELETE, which responds with no content.
+impl Endpoint for MeRecords {
+    type Response = Option<models::Record>;
+}
+
+impl Endpoint for MeWorks {
+    type Response = models::WorksResponse;
+}
+
+impl Endpoint for MePrograms {
+    type Response = models::ProgramsResponse;
+}
+
+impl Endpoint for People {
+    type Response = models::PeopleResponse;
+}
+
+impl Endpoint for Organizations {
+    type Response = models::OrganizationsResponse;
+}
+
+impl Endpoint for Series {
+    type Response = models::SeriesResponse;
+}
+
+impl Endpoint for Characters {
+    type Response = models::CharactersResponse;
+}
+
+impl Endpoint for Casts {
+    type Response = models::CastsResponse;
+}
+
+impl Endpoint for Staffs {
+    type Response = models::StaffsResponse;
+}
+
+impl Endpoint for Info {
+    type Response = models::TokenInfo;
+}
+
+impl Endpoint for Revoke {
+    type Response = models::Revoked;
+}
+
+/// A list response which points to its next page.
+
+pub trait Page {
+    type Item;
+    fn next_page(&self) -> Option<u64>;
+    fn into_items(self) -> Vec<Self::Item>;
+}
+
+/// A parameter type of the endpoints which accept `page`.   
+/// Used by `Client::paginate()`.
+
+pub trait Paginate: Endpoint {
+    fn page_key() -> Self;
+}
+
+impl Paginate for Works {
+    fn page_key() -> Self {
+        Works::page
+    }
+}
+
+impl Paginate for Episodes {
+    fn page_key() -> Self {
+        Episodes::page
+    }
+}
+
+impl Paginate for Records {
+    fn page_key() -> Self {
+        Records::page
+    }
+}
+
+impl Paginate for Reviews {
+    fn page_key() -> Self {
+        Reviews::page
+    }
+}
+
+impl Paginate for Users {
+    fn page_key() -> Self {
+        Users::page
+    }
+}
+
+impl Paginate for Following {
+    fn page_key() -> Self {
+        Following::page
+    }
+}
+
+impl Paginate for Followers {
+    fn page_key() -> Self {
+        Followers::page
+    }
+}
+
+impl Paginate for Activities {
+    fn page_key() -> Self {
+        Activities::page
+    }
+}
+
+impl Paginate for MeFollowing_activities {
+    fn page_key() -> Self {
+        MeFollowing_activities::page
+    }
+}
+
+impl Paginate for MeWorks {
+    fn page_key() -> Self {
+        MeWorks::page
+    }
+}
+
+impl Paginate for MePrograms {
+    fn page_key() -> Self {
+        MePrograms::page
+    }
+}
+
+impl Paginate for People {
+    fn page_key() -> Self {
+        People::page
+    }
+}
+
+impl Paginate for Organizations {
+    fn page_key() -> Self {
+        Organizations::page
+    }
+}
+
+impl Paginate for Series {
+    fn page_key() -> Self {
+        Series::page
+    }
+}
+
+impl Paginate for Characters {
+    fn page_key() -> Self {
+        Characters::page
+    }
+}
+
+impl Paginate for Casts {
+    fn page_key() -> Self {
+        Casts::page
+    }
+}
+
+impl Paginate for Staffs {
+    fn page_key() -> Self {
+        Staffs::page
+    }
+}
+
+/// Decodes a response of /oauth/token.
+
+pub(crate) fn decode_token(
+    status: reqwest::StatusCode,
+    headers: &reqwest::header::HeaderMap,
+    body: &str,
+) -> Result<models::TokenResponse, Error> {
+    if status.is_success() {
+        decode(body)
+    } else {
+        Err(Error::from_oauth_response(status, headers, body))
+    }
+}
+
+/// Decodes a response body. An empty body (204 No Content) is read as `null`.
+
+pub(crate) fn decode<T: DeserializeOwned>(body: &str) -> Result<T, Error> {
+    if body.trim().is_empty() {
+        serde_json::from_str("null").map_err(Into::into)
+    } else {
+        serde_json::from_str(body).map_err(Into::into)
+    }
+}
+
 /// used by works() function   
 /// /v1/works assepts parameters.
 
@@ -925,13 +1803,21 @@ impl From<Works> for String {
 
 impl From<&'static str> for Works {
     fn from(p: &'static str) -> Self {
-        serde_json::from_str(p).unwrap_or(Works::Invalid)
+        p.parse().unwrap_or(Works::Invalid)
     }
 }
 
 impl From<String> for Works {
     fn from(p: String) -> Self {
-        serde_json::from_str(p.as_str()).unwrap_or(Works::Invalid)
+        p.parse().unwrap_or(Works::Invalid)
+    }
+}
+
+impl FromStr for Works {
+    type Err = Error;
+
+    fn from_str(p: &str) -> Result<Self, Error> {
+        parse_param(p)
     }
 }
 
@@ -971,13 +1857,21 @@ impl From<Episodes> for String {
 
 impl From<&'static str> for Episodes {
     fn from(p: &'static str) -> Episodes {
-        serde_json::from_str(p).unwrap_or(Episodes::Invalid)
+        p.parse().unwrap_or(Episodes::Invalid)
     }
 }
 
 impl From<String> for Episodes {
     fn from(p: String) -> Self {
-        serde_json::from_str(p.as_str()).unwrap_or(Episodes::Invalid)
+        p.parse().unwrap_or(Episodes::Invalid)
+    }
+}
+
+impl FromStr for Episodes {
+    type Err = Error;
+
+    fn from_str(p: &str) -> Result<Self, Error> {
+        parse_param(p)
     }
 }
 
@@ -1017,13 +1911,21 @@ impl From<Records> for String {
 
 impl From<&'static str> for Records {
     fn from(p: &'static str) -> Self {
-        serde_json::from_str(p).unwrap_or(Records::Invalid)
+        p.parse().unwrap_or(Records::Invalid)
     }
 }
 
 impl From<String> for Records {
     fn from(p: String) -> Self {
-        serde_json::from_str(p.as_str()).unwrap_or(Records::Invalid)
+        p.parse().unwrap_or(Records::Invalid)
+    }
+}
+
+impl FromStr for Records {
+    type Err = Error;
+
+    fn from_str(p: &str) -> Result<Self, Error> {
+        parse_param(p)
     }
 }
 
@@ -1058,13 +1960,21 @@ impl From<MeStatuses> for String {
 
 impl From<&'static str> for MeStatuses {
     fn from(p: &'static str) -> Self {
-        serde_json::from_str(p).unwrap_or(MeStatuses::Invalid)
+        p.parse().unwrap_or(MeStatuses::Invalid)
     }
 }
 
 impl From<String> for MeStatuses {
     fn from(p: String) -> Self {
-        serde_json::from_str(p.as_str()).unwrap_or(MeStatuses::Invalid)
+        p.parse().unwrap_or(MeStatuses::Invalid)
+    }
+}
+
+impl FromStr for MeStatuses {
+    type Err = Error;
+
+    fn from_str(p: &str) -> Result<Self, Error> {
+        parse_param(p)
     }
 }
 
@@ -1083,6 +1993,7 @@ pub enum MeRecords {
     episode_id,
     comment,
     rating,
+    rating_state,
     share_twitter,
     share_facebook,
     Invalid,
@@ -1102,13 +2013,21 @@ impl From<MeRecords> for String {
 
 impl From<&'static str> for MeRecords {
     fn from(p: &'static str) -> Self {
-        serde_json::from_str(p).unwrap_or(MeRecords::Invalid)
+        p.parse().unwrap_or(MeRecords::Invalid)
     }
 }
 
 impl From<String> for MeRecords {
     fn from(p: String) -> Self {
-        serde_json::from_str(p.as_str()).unwrap_or(MeRecords::Invalid)
+        p.parse().unwrap_or(MeRecords::Invalid)
+    }
+}
+
+impl FromStr for MeRecords {
+    type Err = Error;
+
+    fn from_str(p: &str) -> Result<Self, Error> {
+        parse_param(p)
     }
 }
 
@@ -1151,13 +2070,21 @@ impl From<MeWorks> for String {
 
 impl From<&'static str> for MeWorks {
     fn from(p: &'static str) -> Self {
-        serde_json::from_str(p).unwrap_or(MeWorks::Invalid)
+        p.parse().unwrap_or(MeWorks::Invalid)
     }
 }
 
 impl From<String> for MeWorks {
     fn from(p: String) -> Self {
-        serde_json::from_str(p.as_str()).unwrap_or(MeWorks::Invalid)
+        p.parse().unwrap_or(MeWorks::Invalid)
+    }
+}
+
+impl FromStr for MeWorks {
+    type Err = Error;
+
+    fn from_str(p: &str) -> Result<Self, Error> {
+        parse_param(p)
     }
 }
 
@@ -1202,13 +2129,21 @@ impl From<MePrograms> for String {
 
 impl From<&'static str> for MePrograms {
     fn from(p: &'static str) -> Self {
-        serde_json::from_str(p).unwrap_or(MePrograms::Invalid)
+        p.parse().unwrap_or(MePrograms::Invalid)
     }
 }
 
 impl From<String> for MePrograms {
     fn from(p: String) -> Self {
-        serde_json::from_str(p.as_str()).unwrap_or(MePrograms::Invalid)
+        p.parse().unwrap_or(MePrograms::Invalid)
+    }
+}
+
+impl FromStr for MePrograms {
+    type Err = Error;
+
+    fn from_str(p: &str) -> Result<Self, Error> {
+        parse_param(p)
     }
 }
 
@@ -1225,8 +2160,9 @@ impl fmt::Display for MePrograms {
 pub fn people() -> Service<People>{
     Service{
         method: reqwest::Method::GET,
-        url: "https://api.annict.com/v1/people".to_string(),
+        path: "/v1/people".to_string(),
         params: None,
+        encoding: Encoding::Query,
     }
 }
 
@@ -1259,13 +2195,21 @@ impl From<People> for String {
 
 impl From<&'static str> for People {
     fn from(p: &'static str) -> Self {
-        serde_json::from_str(p).unwrap_or(People::Invalid)
+        p.parse().unwrap_or(People::Invalid)
     }
 }
 
 impl From<String> for People {
     fn from(p: String) -> Self {
-        serde_json::from_str(p.as_str()).unwrap_or(People::Invalid)
+        p.parse().unwrap_or(People::Invalid)
+    }
+}
+
+impl FromStr for People {
+    type Err = Error;
+
+    fn from_str(p: &str) -> Result<Self, Error> {
+        parse_param(p)
     }
 }
 
@@ -1281,8 +2225,9 @@ impl fmt::Display for People {
 pub fn organizations() -> Service<Organizations>{
     Service{
         method: reqwest::Method::GET,
-        url: "https://api.annict.com/v1/organizations".to_string(),
+        path: "/v1/organizations".to_string(),
         params: None,
+        encoding: Encoding::Query,
     }
 }
 
@@ -1315,13 +2260,21 @@ impl From<Organizations> for String {
 
 impl From<&'static str> for Organizations {
     fn from(p: &'static str) -> Self {
-        serde_json::from_str(p).unwrap_or(Organizations::Invalid)
+        p.parse().unwrap_or(Organizations::Invalid)
     }
 }
 
 impl From<String> for Organizations {
     fn from(p: String) -> Self {
-        serde_json::from_str(p.as_str()).unwrap_or(Organizations::Invalid)
+        p.parse().unwrap_or(Organizations::Invalid)
+    }
+}
+
+impl FromStr for Organizations {
+    type Err = Error;
+
+    fn from_str(p: &str) -> Result<Self, Error> {
+        parse_param(p)
     }
 }
 
@@ -1337,8 +2290,9 @@ impl fmt::Display for Organizations {
 pub fn series() -> Service<Series>{
     Service{
         method: reqwest::Method::GET,
-        url: "https://api.annict.com/v1/series".to_string(),
+        path: "/v1/series".to_string(),
         params: None,
+        encoding: Encoding::Query,
     }
 }
 
@@ -1371,13 +2325,21 @@ impl From<Series> for String {
 
 impl From<&'static str> for Series {
     fn from(p: &'static str) -> Self {
-        serde_json::from_str(p).unwrap_or(Series::Invalid)
+        p.parse().unwrap_or(Series::Invalid)
     }
 }
 
 impl From<String> for Series {
     fn from(p: String) -> Self {
-        serde_json::from_str(p.as_str()).unwrap_or(Series::Invalid)
+        p.parse().unwrap_or(Series::Invalid)
+    }
+}
+
+impl FromStr for Series {
+    type Err = Error;
+
+    fn from_str(p: &str) -> Result<Self, Error> {
+        parse_param(p)
     }
 }
 
@@ -1393,8 +2355,9 @@ impl fmt::Display for Series {
 pub fn characters() -> Service<Characters>{
     Service{
         method: reqwest::Method::GET,
-        url: "https://api.annict.com/v1/characters".to_string(),
+        path: "/v1/characters".to_string(),
         params: None,
+        encoding: Encoding::Query,
     }
 }
 
@@ -1427,13 +2390,21 @@ impl From<Characters> for String {
 
 impl From<&'static str> for Characters {
     fn from(p: &'static str) -> Self {
-        serde_json::from_str(p).unwrap_or(Characters::Invalid)
+        p.parse().unwrap_or(Characters::Invalid)
     }
 }
 
 impl From<String> for Characters {
     fn from(p: String) -> Self {
-        serde_json::from_str(p.as_str()).unwrap_or(Characters::Invalid)
+        p.parse().unwrap_or(Characters::Invalid)
+    }
+}
+
+impl FromStr for Characters {
+    type Err = Error;
+
+    fn from_str(p: &str) -> Result<Self, Error> {
+        parse_param(p)
     }
 }
 
@@ -1449,8 +2420,9 @@ impl fmt::Display for Characters {
 pub fn casts() -> Service<Casts>{
     Service{
         method: reqwest::Method::GET,
-        url: "https://api.annict.com/v1/casts".to_string(),
+        path: "/v1/casts".to_string(),
         params: None,
+        encoding: Encoding::Query,
     }
 }
 
@@ -1484,13 +2456,21 @@ impl From<Casts> for String {
 
 impl From<&'static str> for Casts {
     fn from(p: &'static str) -> Self {
-        serde_json::from_str(p).unwrap_or(Casts::Invalid)
+        p.parse().unwrap_or(Casts::Invalid)
     }
 }
 
 impl From<String> for Casts {
     fn from(p: String) -> Self {
-        serde_json::from_str(p.as_str()).unwrap_or(Casts::Invalid)
+        p.parse().unwrap_or(Casts::Invalid)
+    }
+}
+
+impl FromStr for Casts {
+    type Err = Error;
+
+    fn from_str(p: &str) -> Result<Self, Error> {
+        parse_param(p)
     }
 }
 
@@ -1506,8 +2486,9 @@ impl fmt::Display for Casts {
 pub fn staffs() -> Service<Staffs>{
     Service{
         method: reqwest::Method::GET,
-        url: "https://api.annict.com/v1/staffs".to_string(),
+        path: "/v1/staffs".to_string(),
         params: None,
+        encoding: Encoding::Query,
     }
 }
 
@@ -1540,84 +2521,26 @@ impl From<Staffs> for String {
 
 impl From<&'static str> for Staffs {
     fn from(p: &'static str) -> Self {
-        serde_json::from_str(p).unwrap_or(Staffs::Invalid)
+        p.parse().unwrap_or(Staffs::Invalid)
     }
 }
 
 impl From<String> for Staffs {
     fn from(p: String) -> Self {
-        serde_json::from_str(p.as_str()).unwrap_or(Staffs::Invalid)
+        p.parse().unwrap_or(Staffs::Invalid)
     }
 }
 
-impl fmt::Display for Staffs {
-    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
-        write!(f, "{:?}", self)
-    }
-}
+impl FromStr for Staffs {
+    type Err = Error;
 
-// -------------------------------------------------
-
-#[derive(Fail, Debug)]
-pub enum ErrorKind {
-    #[fail(display = "Invalid value at token or request parameters")]
-    InvalidValue,
-}
-
-/* ----------- failure boilerplate ----------- */
-
-use failure::{Backtrace, Context, Fail};
-use std::fmt::Display;
-
-#[derive(Debug)]
-pub struct Error {
-    inner: Context<ErrorKind>,
-}
-
-impl Fail for Error {
-    fn cause(&self) -> Option<&dyn Fail> {
-        self.inner.cause()
-    }
-
-    fn backtrace(&self) -> Option<&Backtrace> {
-        self.inner.backtrace()
+    fn from_str(p: &str) -> Result<Self, Error> {
+        parse_param(p)
     }
 }
 
-impl Display for Error {
+impl fmt::Display for Staffs {
     fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
-        Display::fmt(&self.inner, f)
-    }
-}
-
-impl Error {
-    pub fn new(inner: Context<ErrorKind>) -> Error {
-        Error { inner }
-    }
-
-    pub fn kind(&self) -> &ErrorKind {
-        self.inner.get_context()
-    }
-}
-
-impl From<ErrorKind> for Error {
-    fn from(kind: ErrorKind) -> Error {
-        Error {
-            inner: Context::new(kind),
-        }
-    }
-}
-
-impl From<Context<ErrorKind>> for Error {
-    fn from(inner: Context<ErrorKind>) -> Error {
-        Error { inner }
-    }
-}
-
-impl From<reqwest::Error> for Error {
-    fn from(error: reqwest::Error) -> Error {
-        Error {
-            inner: error.context(ErrorKind::InvalidValue),
-        }
+        write!(f, "{:?}", self)
     }
 }
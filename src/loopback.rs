@@ -0,0 +1,194 @@
+use crate::auth::{AccessToken, AuthorizeUrl, OAuth};
+use crate::models::TokenResponse;
+use crate::{random_state, verify_callback, Error, ErrorKind};
+use std::io::{BufRead, BufReader, ErrorKind as IoErrorKind, Write};
+use std::net::{TcpListener, TcpStream};
+use std::thread;
+use std::time::{Duration, Instant};
+
+const CALLBACK_PATH: &str = "/callback";
+/// How long a connection may take to send its request line and headers.
+const READ_TIMEOUT: Duration = Duration::from_secs(2);
+/// How often the listener is polled for a connection.
+const POLL_INTERVAL: Duration = Duration::from_millis(50);
+
+/// A login which receives the authorization code on a local port,
+/// instead of having the user copy it from the browser.
+///
+/// Register `http://127.0.0.1:<port>/callback` as a redirect URI of the application.
+/// Redirects with a wrong `state` are answered with 400 and ignored.
+/// `wait()` gives up with `ErrorKind::Timeout` after 5 minutes, which `timeout()` changes.
+///
+/// Examples
+/// ========
+/// ```rust
+/// # use annis::{Client, OAuth, Scope, Error};
+/// #
+/// # fn run() -> Result<(), Error> {
+/// let auth = OAuth::client_id("client_id");
+///
+/// let mut login = auth.loopback(8080)?;
+/// login.client_secret("client_secret_key").scope(Scope::Read | Scope::Write);
+///
+/// // -> Open this URL in a browser.
+/// println!("{}", login.authorize_url());
+///
+/// let token = login.wait()?;
+/// let client = Client::set_token(token.access_token);
+/// # Ok(())
+/// # }
+/// ```
+
+#[derive(Debug)]
+pub struct LoopbackLogin {
+    listener: TcpListener,
+    state: String,
+    authorize_url: AuthorizeUrl,
+    access_token: AccessToken,
+    timeout: Duration,
+}
+
+impl OAuth {
+    /// Listen on `127.0.0.1:<port>` for the redirect from Annict. Port 0 picks a free port.
+    /// Fails with `InvalidParameter` if `authorize_base_url` is not a valid URL.
+    pub fn loopback(&self, port: u16) -> Result<LoopbackLogin, Error> {
+        let listener = TcpListener::bind(("127.0.0.1", port))?;
+        let redirect_uri = format!(
+            "http://127.0.0.1:{}{}",
+            listener.local_addr()?.port(),
+            CALLBACK_PATH
+        );
+        let state = random_state();
+        let mut authorize_url = self.authorize_url();
+        authorize_url
+            .redirect_uri(redirect_uri.as_str())
+            .state(state.as_str());
+        authorize_url.try_build()?;
+        let mut access_token = self.access_token();
+        access_token.redirect_uri(redirect_uri);
+        Ok(LoopbackLogin {
+            listener,
+            state,
+            authorize_url,
+            access_token,
+            timeout: Duration::from_secs(5 * 60),
+        })
+    }
+}
+
+impl LoopbackLogin {
+    pub fn scope<P>(&mut self, scope: P) -> &mut Self
+    where
+        P: Into<String>,
+    {
+        self.authorize_url.scope(scope);
+        self
+    }
+
+    pub fn client_secret<P>(&mut self, client_secret: P) -> &mut Self
+    where
+        P: Into<String>,
+    {
+        self.access_token.client_secret(client_secret);
+        self
+    }
+
+    /// How long `wait()` and `wait_for_code()` wait for the redirect.
+    pub fn timeout(&mut self, timeout: Duration) -> &mut Self {
+        self.timeout = timeout;
+        self
+    }
+
+    pub fn redirect_uri(&self) -> &str {
+        &self.access_token.redirect_uri
+    }
+
+    /// The `state` sent with the authorize URL and expected back in the redirect.
+    pub fn state(&self) -> &str {
+        &self.state
+    }
+
+    /// The URL to open in a browser.
+    pub fn authorize_url(&self) -> String {
+        self.authorize_url.build()
+    }
+
+    /// Wait for the redirect and return the authorization code in it.
+    pub fn wait_for_code(&self) -> Result<String, Error> {
+        let deadline = Instant::now() + self.timeout;
+        self.listener.set_nonblocking(true)?;
+        loop {
+            let mut stream = match self.listener.accept() {
+                Ok((stream, _)) => stream,
+                Err(e) if e.kind() == IoErrorKind::WouldBlock => {
+                    if Instant::now() >= deadline {
+                        return Err(ErrorKind::Timeout.into());
+                    }
+                    thread::sleep(POLL_INTERVAL);
+                    continue;
+                }
+                Err(e) => return Err(e.into()),
+            };
+            stream.set_nonblocking(false)?;
+            stream.set_read_timeout(Some(READ_TIMEOUT))?;
+            stream.set_write_timeout(Some(READ_TIMEOUT))?;
+            let target = match read_request_target(&mut stream) {
+                Some(target) => target,
+                None => continue,
+            };
+            if target.split('?').next() != Some(CALLBACK_PATH) {
+                respond(&mut stream, "404 Not Found", "Not Found");
+                continue;
+            }
+            let code = verify_callback(&target, &self.state);
+            match &code {
+                Ok(_) => respond(
+                    &mut stream,
+                    "200 OK",
+                    "Logged in to Annict. You can close this window.",
+                ),
+                // Not the redirect of this login, e.g. a request made by another page.
+                Err(e) if matches!(e.kind(), ErrorKind::StateMismatch) => {
+                    respond(&mut stream, "400 Bad Request", "Failed to log in to Annict.");
+                    continue;
+                }
+                Err(_) => respond(&mut stream, "400 Bad Request", "Failed to log in to Annict."),
+            };
+            return code;
+        }
+    }
+
+    /// Wait for the redirect and exchange its code for an access token.
+    pub fn wait(&self) -> Result<TokenResponse, Error> {
+        let code = self.wait_for_code()?;
+        self.access_token.clone().code(code).build()
+    }
+}
+
+fn read_request_target(stream: &mut TcpStream) -> Option<String> {
+    let mut reader = BufReader::new(stream);
+    let mut line = String::new();
+    reader.read_line(&mut line).ok()?;
+    let target = line.split_whitespace().nth(1)?.to_string();
+    // Drain the headers so the browser gets the response.
+    loop {
+        let mut header = String::new();
+        match reader.read_line(&mut header) {
+            Ok(n) if n > 0 && !header.trim().is_empty() => continue,
+            _ => break,
+        }
+    }
+    Some(target)
+}
+
+fn respond(stream: &mut TcpStream, status: &str, message: &str) {
+    let body = format!("<!DOCTYPE html><html><body><p>{}</p></body></html>", message);
+    let _ = write!(
+        stream,
+        "HTTP/1.1 {}\r\nContent-Type: text/html; charset=utf-8\r\nContent-Length: {}\r\nConnection: close\r\n\r\n{}",
+        status,
+        body.len(),
+        body
+    );
+    let _ = stream.flush();
+}
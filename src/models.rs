@@ -0,0 +1,775 @@
+//! Typed response models of the Annict API.
+//!
+//! Each list endpoint returns an envelope such as `WorksResponse` which holds
+//! the requested resources and the paging information.
+
+use crate::{Page, RatingState, Scope, Scopes, WatchStatus};
+use serde::de::{Deserializer, IgnoredAny};
+use serde::{Deserialize, Serialize};
+use std::fmt;
+
+/// A work (anime title) object.
+
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct Work {
+    pub id: u64,
+    pub title: String,
+    pub title_kana: Option<String>,
+    pub media: Option<String>,
+    pub media_text: Option<String>,
+    pub season_name: Option<String>,
+    pub season_name_text: Option<String>,
+    pub released_on: Option<String>,
+    pub released_on_about: Option<String>,
+    pub official_site_url: Option<String>,
+    pub wikipedia_url: Option<String>,
+    pub twitter_username: Option<String>,
+    pub twitter_hashtag: Option<String>,
+    pub syobocal_tid: Option<String>,
+    pub mal_anime_id: Option<String>,
+    pub images: Option<WorkImages>,
+    pub episodes_count: Option<u64>,
+    pub watchers_count: Option<u64>,
+    pub reviews_count: Option<u64>,
+    pub no_episodes: Option<bool>,
+}
+
+/// Images of a work.
+
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct WorkImages {
+    pub recommended_url: Option<String>,
+    pub facebook: Option<FacebookImages>,
+    pub twitter: Option<TwitterImages>,
+}
+
+/// Facebook images of a work.
+
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct FacebookImages {
+    pub og_image_url: Option<String>,
+}
+
+/// Twitter images of a work.
+
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct TwitterImages {
+    pub mini_avatar_url: Option<String>,
+    pub normal_avatar_url: Option<String>,
+    pub bigger_avatar_url: Option<String>,
+    pub original_avatar_url: Option<String>,
+    pub image_url: Option<String>,
+}
+
+/// An episode object.
+
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct Episode {
+    pub id: u64,
+    pub number: Option<String>,
+    pub number_text: Option<String>,
+    pub sort_number: Option<u64>,
+    pub title: Option<String>,
+    pub records_count: Option<u64>,
+    pub record_comments_count: Option<u64>,
+    pub work: Option<Work>,
+    pub prev_episode: Option<Box<Episode>>,
+    pub next_episode: Option<Box<Episode>>,
+}
+
+/// A record object.
+
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct Record {
+    pub id: u64,
+    pub comment: Option<String>,
+    pub rating: Option<f64>,
+    pub rating_state: Option<RatingState>,
+    pub is_modified: Option<bool>,
+    pub likes_count: Option<u64>,
+    pub comments_count: Option<u64>,
+    pub created_at: Option<String>,
+    pub user: Option<User>,
+    pub work: Option<Work>,
+    pub episode: Option<Episode>,
+}
+
+/// A review object.
+
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct Review {
+    pub id: u64,
+    pub title: Option<String>,
+    pub body: Option<String>,
+    pub rating_animation_state: Option<RatingState>,
+    pub rating_music_state: Option<RatingState>,
+    pub rating_story_state: Option<RatingState>,
+    pub rating_character_state: Option<RatingState>,
+    pub rating_overall_state: Option<RatingState>,
+    pub likes_count: Option<u64>,
+    pub impressions_count: Option<u64>,
+    pub modified_at: Option<String>,
+    pub created_at: Option<String>,
+    pub user: Option<User>,
+    pub work: Option<Work>,
+}
+
+/// A user object.
+///
+/// `email` and `notifications_count` are only returned by /v1/me.
+
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct User {
+    pub id: u64,
+    pub username: String,
+    pub name: Option<String>,
+    pub description: Option<String>,
+    pub url: Option<String>,
+    pub avatar_url: Option<String>,
+    pub background_image_url: Option<String>,
+    pub records_count: Option<u64>,
+    pub followings_count: Option<u64>,
+    pub followers_count: Option<u64>,
+    pub wanna_watch_count: Option<u64>,
+    pub watching_count: Option<u64>,
+    pub watched_count: Option<u64>,
+    pub on_hold_count: Option<u64>,
+    pub stop_watching_count: Option<u64>,
+    pub created_at: Option<String>,
+    pub email: Option<String>,
+    pub notifications_count: Option<u64>,
+}
+
+/// An activity object.
+///
+/// Which of `work`, `episode`, `record`, `review`, `multiple_records` and
+/// `status` are present depends on `action`.
+
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct Activity {
+    pub id: u64,
+    pub action: String,
+    pub created_at: Option<String>,
+    pub user: Option<User>,
+    pub work: Option<Work>,
+    pub episode: Option<Episode>,
+    pub record: Option<Record>,
+    pub review: Option<Review>,
+    pub multiple_records: Option<Vec<MultipleRecord>>,
+    pub status: Option<Status>,
+}
+
+/// An element of `Activity::multiple_records`.
+
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct MultipleRecord {
+    pub episode: Option<Episode>,
+    pub record: Option<Record>,
+}
+
+/// A watching status attached to an activity.
+
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct Status {
+    pub kind: WatchStatus,
+}
+
+/// A broadcast program object.
+
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct Program {
+    pub id: u64,
+    pub started_at: Option<String>,
+    pub is_rebroadcast: Option<bool>,
+    pub channel: Option<Channel>,
+    pub work: Option<Work>,
+    pub episode: Option<Episode>,
+}
+
+/// A broadcast channel object.
+
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct Channel {
+    pub id: u64,
+    pub name: String,
+}
+
+/// A person object.
+
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct Person {
+    pub id: u64,
+    pub name: String,
+    pub name_kana: Option<String>,
+    pub name_en: Option<String>,
+    pub nickname: Option<String>,
+    pub nickname_en: Option<String>,
+    pub gender_text: Option<String>,
+    pub url: Option<String>,
+    pub url_en: Option<String>,
+    pub wikipedia_url: Option<String>,
+    pub wikipedia_url_en: Option<String>,
+    pub twitter_username: Option<String>,
+    pub twitter_username_en: Option<String>,
+    pub birthday: Option<String>,
+    pub blood_type: Option<String>,
+    pub height: Option<u64>,
+    pub favorite_people_count: Option<u64>,
+    pub casts_count: Option<u64>,
+    pub staffs_count: Option<u64>,
+    pub prefecture: Option<Prefecture>,
+}
+
+/// A prefecture object.
+
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct Prefecture {
+    pub id: u64,
+    pub name: String,
+}
+
+/// An organization object.
+
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct Organization {
+    pub id: u64,
+    pub name: String,
+    pub name_kana: Option<String>,
+    pub name_en: Option<String>,
+    pub url: Option<String>,
+    pub url_en: Option<String>,
+    pub wikipedia_url: Option<String>,
+    pub wikipedia_url_en: Option<String>,
+    pub twitter_username: Option<String>,
+    pub twitter_username_en: Option<String>,
+    pub favorite_organizations_count: Option<u64>,
+    pub staffs_count: Option<u64>,
+}
+
+/// A series object.
+
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct Series {
+    pub id: u64,
+    pub name: String,
+    pub name_ro: Option<String>,
+    pub name_en: Option<String>,
+}
+
+/// A character object.
+
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct Character {
+    pub id: u64,
+    pub name: String,
+    pub name_kana: Option<String>,
+    pub name_en: Option<String>,
+    pub nickname: Option<String>,
+    pub nickname_en: Option<String>,
+    pub birthday: Option<String>,
+    pub birthday_en: Option<String>,
+    pub age: Option<String>,
+    pub age_en: Option<String>,
+    pub blood_type: Option<String>,
+    pub blood_type_en: Option<String>,
+    pub height: Option<String>,
+    pub height_en: Option<String>,
+    pub weight: Option<String>,
+    pub weight_en: Option<String>,
+    pub nationality: Option<String>,
+    pub nationality_en: Option<String>,
+    pub occupation: Option<String>,
+    pub occupation_en: Option<String>,
+    pub description: Option<String>,
+    pub description_en: Option<String>,
+    pub description_source: Option<String>,
+    pub description_source_en: Option<String>,
+    pub favorite_characters_count: Option<u64>,
+    pub series: Option<Series>,
+}
+
+/// A cast object.
+
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct Cast {
+    pub id: u64,
+    pub name: String,
+    pub name_en: Option<String>,
+    pub sort_number: Option<u64>,
+    pub work: Option<Work>,
+    pub character: Option<Character>,
+    pub person: Option<Person>,
+}
+
+/// A staff object.
+///
+/// Either `person` or `organization` is set.
+
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct Staff {
+    pub id: u64,
+    pub name: String,
+    pub name_en: Option<String>,
+    pub role_text: Option<String>,
+    pub role_other: Option<String>,
+    pub role_other_en: Option<String>,
+    pub sort_number: Option<u64>,
+    pub work: Option<Work>,
+    pub person: Option<Person>,
+    pub organization: Option<Organization>,
+}
+
+/// A work decoded from a response with `fields`, where any field may be missing.
+
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+pub struct PartialWork {
+    pub id: Option<u64>,
+    pub title: Option<String>,
+    pub title_kana: Option<String>,
+    pub media: Option<String>,
+    pub media_text: Option<String>,
+    pub season_name: Option<String>,
+    pub season_name_text: Option<String>,
+    pub released_on: Option<String>,
+    pub released_on_about: Option<String>,
+    pub official_site_url: Option<String>,
+    pub wikipedia_url: Option<String>,
+    pub twitter_username: Option<String>,
+    pub twitter_hashtag: Option<String>,
+    pub syobocal_tid: Option<String>,
+    pub mal_anime_id: Option<String>,
+    pub images: Option<WorkImages>,
+    pub episodes_count: Option<u64>,
+    pub watchers_count: Option<u64>,
+    pub reviews_count: Option<u64>,
+    pub no_episodes: Option<bool>,
+}
+
+/// An episode decoded from a response with `fields`.
+
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+pub struct PartialEpisode {
+    pub id: Option<u64>,
+    pub number: Option<String>,
+    pub number_text: Option<String>,
+    pub sort_number: Option<u64>,
+    pub title: Option<String>,
+    pub records_count: Option<u64>,
+    pub record_comments_count: Option<u64>,
+    pub work: Option<PartialWork>,
+    pub prev_episode: Option<Box<PartialEpisode>>,
+    pub next_episode: Option<Box<PartialEpisode>>,
+}
+
+/// A record decoded from a response with `fields`.
+
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+pub struct PartialRecord {
+    pub id: Option<u64>,
+    pub comment: Option<String>,
+    pub rating: Option<f64>,
+    pub rating_state: Option<RatingState>,
+    pub is_modified: Option<bool>,
+    pub likes_count: Option<u64>,
+    pub comments_count: Option<u64>,
+    pub created_at: Option<String>,
+    pub user: Option<PartialUser>,
+    pub work: Option<PartialWork>,
+    pub episode: Option<PartialEpisode>,
+}
+
+/// A review decoded from a response with `fields`.
+
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+pub struct PartialReview {
+    pub id: Option<u64>,
+    pub title: Option<String>,
+    pub body: Option<String>,
+    pub rating_animation_state: Option<RatingState>,
+    pub rating_music_state: Option<RatingState>,
+    pub rating_story_state: Option<RatingState>,
+    pub rating_character_state: Option<RatingState>,
+    pub rating_overall_state: Option<RatingState>,
+    pub likes_count: Option<u64>,
+    pub impressions_count: Option<u64>,
+    pub modified_at: Option<String>,
+    pub created_at: Option<String>,
+    pub user: Option<PartialUser>,
+    pub work: Option<PartialWork>,
+}
+
+/// A user decoded from a response with `fields`.
+
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+pub struct PartialUser {
+    pub id: Option<u64>,
+    pub username: Option<String>,
+    pub name: Option<String>,
+    pub description: Option<String>,
+    pub url: Option<String>,
+    pub avatar_url: Option<String>,
+    pub background_image_url: Option<String>,
+    pub records_count: Option<u64>,
+    pub followings_count: Option<u64>,
+    pub followers_count: Option<u64>,
+    pub wanna_watch_count: Option<u64>,
+    pub watching_count: Option<u64>,
+    pub watched_count: Option<u64>,
+    pub on_hold_count: Option<u64>,
+    pub stop_watching_count: Option<u64>,
+    pub created_at: Option<String>,
+    pub email: Option<String>,
+    pub notifications_count: Option<u64>,
+}
+
+/// A broadcast program decoded from a response with `fields`.
+
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+pub struct PartialProgram {
+    pub id: Option<u64>,
+    pub started_at: Option<String>,
+    pub is_rebroadcast: Option<bool>,
+    pub channel: Option<Channel>,
+    pub work: Option<PartialWork>,
+    pub episode: Option<PartialEpisode>,
+}
+
+/// An activity decoded from a response with `fields`.
+
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+pub struct PartialActivity {
+    pub id: Option<u64>,
+    pub action: Option<String>,
+    pub created_at: Option<String>,
+    pub user: Option<PartialUser>,
+    pub work: Option<PartialWork>,
+    pub episode: Option<PartialEpisode>,
+    pub record: Option<PartialRecord>,
+    pub review: Option<PartialReview>,
+    pub multiple_records: Option<Vec<PartialMultipleRecord>>,
+    pub status: Option<Status>,
+}
+
+/// An element of `PartialActivity::multiple_records`.
+
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+pub struct PartialMultipleRecord {
+    pub episode: Option<PartialEpisode>,
+    pub record: Option<PartialRecord>,
+}
+
+/// A person decoded from a response with `fields`.
+
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+pub struct PartialPerson {
+    pub id: Option<u64>,
+    pub name: Option<String>,
+    pub name_kana: Option<String>,
+    pub name_en: Option<String>,
+    pub nickname: Option<String>,
+    pub nickname_en: Option<String>,
+    pub gender_text: Option<String>,
+    pub url: Option<String>,
+    pub url_en: Option<String>,
+    pub wikipedia_url: Option<String>,
+    pub wikipedia_url_en: Option<String>,
+    pub twitter_username: Option<String>,
+    pub twitter_username_en: Option<String>,
+    pub birthday: Option<String>,
+    pub blood_type: Option<String>,
+    pub height: Option<u64>,
+    pub favorite_people_count: Option<u64>,
+    pub casts_count: Option<u64>,
+    pub staffs_count: Option<u64>,
+    pub prefecture: Option<Prefecture>,
+}
+
+/// An organization decoded from a response with `fields`.
+
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+pub struct PartialOrganization {
+    pub id: Option<u64>,
+    pub name: Option<String>,
+    pub name_kana: Option<String>,
+    pub name_en: Option<String>,
+    pub url: Option<String>,
+    pub url_en: Option<String>,
+    pub wikipedia_url: Option<String>,
+    pub wikipedia_url_en: Option<String>,
+    pub twitter_username: Option<String>,
+    pub twitter_username_en: Option<String>,
+    pub favorite_organizations_count: Option<u64>,
+    pub staffs_count: Option<u64>,
+}
+
+/// A series decoded from a response with `fields`.
+
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+pub struct PartialSeries {
+    pub id: Option<u64>,
+    pub name: Option<String>,
+    pub name_ro: Option<String>,
+    pub name_en: Option<String>,
+}
+
+/// A character decoded from a response with `fields`.
+
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+pub struct PartialCharacter {
+    pub id: Option<u64>,
+    pub name: Option<String>,
+    pub name_kana: Option<String>,
+    pub name_en: Option<String>,
+    pub nickname: Option<String>,
+    pub nickname_en: Option<String>,
+    pub birthday: Option<String>,
+    pub birthday_en: Option<String>,
+    pub age: Option<String>,
+    pub age_en: Option<String>,
+    pub blood_type: Option<String>,
+    pub blood_type_en: Option<String>,
+    pub height: Option<String>,
+    pub height_en: Option<String>,
+    pub weight: Option<String>,
+    pub weight_en: Option<String>,
+    pub nationality: Option<String>,
+    pub nationality_en: Option<String>,
+    pub occupation: Option<String>,
+    pub occupation_en: Option<String>,
+    pub description: Option<String>,
+    pub description_en: Option<String>,
+    pub description_source: Option<String>,
+    pub description_source_en: Option<String>,
+    pub favorite_characters_count: Option<u64>,
+    pub series: Option<PartialSeries>,
+}
+
+/// A cast decoded from a response with `fields`.
+
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+pub struct PartialCast {
+    pub id: Option<u64>,
+    pub name: Option<String>,
+    pub name_en: Option<String>,
+    pub sort_number: Option<u64>,
+    pub work: Option<PartialWork>,
+    pub character: Option<PartialCharacter>,
+    pub person: Option<PartialPerson>,
+}
+
+/// A staff decoded from a response with `fields`.
+
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+pub struct PartialStaff {
+    pub id: Option<u64>,
+    pub name: Option<String>,
+    pub name_en: Option<String>,
+    pub role_text: Option<String>,
+    pub role_other: Option<String>,
+    pub role_other_en: Option<String>,
+    pub sort_number: Option<u64>,
+    pub work: Option<PartialWork>,
+    pub person: Option<PartialPerson>,
+    pub organization: Option<PartialOrganization>,
+}
+
+/// Response of /oauth/token.
+
+#[derive(Clone, PartialEq, Serialize, Deserialize)]
+pub struct TokenResponse {
+    pub access_token: String,
+    pub token_type: String,
+    pub scope: Scopes,
+    pub created_at: u64,
+}
+
+impl TokenResponse {
+    pub fn has_scope(&self, scope: Scope) -> bool {
+        self.scope.contains(scope)
+    }
+}
+
+// The token is left out so that logging a client or a TokenStore doesn't leak it.
+impl fmt::Debug for TokenResponse {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.debug_struct("TokenResponse")
+            .field("access_token", &"<redacted>")
+            .field("token_type", &self.token_type)
+            .field("scope", &self.scope)
+            .field("created_at", &self.created_at)
+            .finish()
+    }
+}
+
+/// Response of /oauth/token/info.
+
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct TokenInfo {
+    pub resource_owner_id: u64,
+    #[serde(alias = "scopes")]
+    pub scope: Vec<Scope>,
+    #[serde(alias = "expires_in")]
+    pub expires_in_seconds: Option<u64>,
+    pub application: Application,
+    pub created_at: u64,
+}
+
+impl TokenInfo {
+    /// Whether the token was granted `scope`, e.g. `Scope::Write` to post records.
+    pub fn has_scope(&self, scope: Scope) -> bool {
+        self.scope.contains(&scope)
+    }
+
+    pub fn scopes(&self) -> Scopes {
+        self.scope.iter().copied().collect()
+    }
+}
+
+/// The application which a token was issued to.
+
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct Application {
+    pub uid: String,
+}
+
+/// Response of /oauth/revoke. The token is no longer usable.
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Revoked;
+
+impl<'de> Deserialize<'de> for Revoked {
+    fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        IgnoredAny::deserialize(deserializer)?;
+        Ok(Revoked)
+    }
+}
+
+macro_rules! envelope {
+    ($(#[$attr:meta])* $name:ident, $field:ident, $item:ty) => {
+        $(#[$attr])*
+        #[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+        pub struct $name {
+            pub $field: Vec<$item>,
+            pub total_count: u64,
+            pub next_page: Option<u64>,
+            pub prev_page: Option<u64>,
+        }
+
+        impl Page for $name {
+            type Item = $item;
+
+            fn next_page(&self) -> Option<u64> {
+                self.next_page
+            }
+
+            fn into_items(self) -> Vec<$item> {
+                self.$field
+            }
+        }
+    };
+}
+
+envelope!(
+    /// Response of /v1/works and /v1/me/works.
+    WorksResponse, works, Work
+);
+envelope!(
+    /// Response of /v1/episodes.
+    EpisodesResponse, episodes, Episode
+);
+envelope!(
+    /// Response of /v1/records.
+    RecordsResponse, records, Record
+);
+envelope!(
+    /// Response of /v1/reviews.
+    ReviewsResponse, reviews, Review
+);
+envelope!(
+    /// Response of /v1/users, /v1/following and /v1/followers.
+    UsersResponse, users, User
+);
+envelope!(
+    /// Response of /v1/activities and /v1/me/following_activities.
+    ActivitiesResponse, activities, Activity
+);
+envelope!(
+    /// Response of /v1/me/programs.
+    ProgramsResponse, programs, Program
+);
+envelope!(
+    /// Response of /v1/people.
+    PeopleResponse, people, Person
+);
+envelope!(
+    /// Response of /v1/organizations.
+    OrganizationsResponse, organizations, Organization
+);
+envelope!(
+    /// Response of /v1/series.
+    SeriesResponse, series, Series
+);
+envelope!(
+    /// Response of /v1/characters.
+    CharactersResponse, characters, Character
+);
+envelope!(
+    /// Response of /v1/casts.
+    CastsResponse, casts, Cast
+);
+envelope!(
+    /// Response of /v1/staffs.
+    StaffsResponse, staffs, Staff
+);
+envelope!(
+    /// Response of /v1/works and /v1/me/works with `fields`.
+    PartialWorksResponse, works, PartialWork
+);
+envelope!(
+    /// Response of /v1/episodes with `fields`.
+    PartialEpisodesResponse, episodes, PartialEpisode
+);
+envelope!(
+    /// Response of /v1/records with `fields`.
+    PartialRecordsResponse, records, PartialRecord
+);
+envelope!(
+    /// Response of /v1/reviews with `fields`.
+    PartialReviewsResponse, reviews, PartialReview
+);
+envelope!(
+    /// Response of /v1/users, /v1/following and /v1/followers with `fields`.
+    PartialUsersResponse, users, PartialUser
+);
+envelope!(
+    /// Response of /v1/me/programs with `fields`.
+    PartialProgramsResponse, programs, PartialProgram
+);
+envelope!(
+    /// Response of /v1/activities and /v1/me/following_activities with `fields`.
+    PartialActivitiesResponse, activities, PartialActivity
+);
+envelope!(
+    /// Response of /v1/people with `fields`.
+    PartialPeopleResponse, people, PartialPerson
+);
+envelope!(
+    /// Response of /v1/organizations with `fields`.
+    PartialOrganizationsResponse, organizations, PartialOrganization
+);
+envelope!(
+    /// Response of /v1/series with `fields`.
+    PartialSeriesResponse, series, PartialSeries
+);
+envelope!(
+    /// Response of /v1/characters with `fields`.
+    PartialCharactersResponse, characters, PartialCharacter
+);
+envelope!(
+    /// Response of /v1/casts with `fields`.
+    PartialCastsResponse, casts, PartialCast
+);
+envelope!(
+    /// Response of /v1/staffs with `fields`.
+    PartialStaffsResponse, staffs, PartialStaff
+);
@@ -1,5 +1,5 @@
 pub mod client;
-pub use client::Client;
+pub use client::{Client, ClientBuilder, Paginator};
 
 pub mod auth;
 pub use auth::*;
\ No newline at end of file
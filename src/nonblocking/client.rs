@@ -1,8 +1,20 @@
-use crate::{Error, Service};
-use futures::TryFutureExt;
-use reqwest::Client as AsyncClient;
+use crate::api::{Core, Step};
+use crate::models::Revoked;
+use crate::nonblocking::OAuth;
+use crate::pagination::Cursor;
+use crate::transport::{AsyncTransport, HttpResponse};
+use crate::{
+    decode, Api, Endpoint, Error, ErrorKind, Page, Paginate, RateLimiter, Service, TokenStore,
+};
+use futures::future::BoxFuture;
+use futures::{Stream, TryStreamExt};
+use reqwest::Response;
 use serde::Serialize;
 use std::cmp::PartialEq;
+use std::fmt;
+use std::pin::Pin;
+use std::sync::Arc;
+use std::task::{Context, Poll};
 
 /// A client to make asynchronous request with Service.
 ///
@@ -16,36 +28,312 @@ use std::cmp::PartialEq;
 /// # #[tokio::main]
 /// # async fn run() -> Result<(), Error> {
 /// let client = Client::set_token("access_token");
-/// let res = client.call(annis::works()).await?.json().await?;
+/// let res = client.call(annis::works()).await?.json::<Value>().await?;
 /// #   Ok(())
 /// # }
 ///```
+///
+/// The client keeps a pool of connections, which its clones share.
+/// Clone it instead of making a new one for each request.
 
-#[derive(Debug, Clone)]
+#[derive(Clone)]
 pub struct Client {
     pub token: String,
+    core: Core,
+    transport: Arc<dyn AsyncTransport>,
 }
 
+/// A builder to make Client. See `annis::ClientBuilder` for its settings.
+
+pub type ClientBuilder = crate::builder::ClientBuilder<Client>;
+
 impl Client {
     pub fn set_token<T>(access_token: T) -> Self
     where
         T: Into<String>,
     {
-        Client {
-            token: access_token.into(),
+        Client::builder().token(access_token).build()
+    }
+
+    pub fn builder() -> ClientBuilder {
+        ClientBuilder::default()
+    }
+
+    /// Make a client with the token saved in `store`.   
+    /// Returns `ErrorKind::MissingToken` if the store is empty.
+    pub fn from_store<S>(store: S) -> Result<Self, Error>
+    where
+        S: TokenStore + 'static,
+    {
+        Client::builder().build_with_store(store)
+    }
+
+    /// The base URL which paths of Service are joined to.
+    pub fn base_url(&self) -> &str {
+        &self.core.base_url
+    }
+
+    /// The RateLimiter set by `ClientBuilder::rate_limit()`.
+    /// It is shared by the clones of this client.
+    pub fn rate_limiter(&self) -> Option<&RateLimiter> {
+        self.core.rate_limit.as_ref()
+    }
+
+    /// Revoke the token of this client, and clear the store it was loaded from.
+    pub async fn revoke(&self) -> Result<Revoked, Error> {
+        let revoked = self.fetch(OAuth::revoke(self.token.as_str())).await?;
+        if let Some(store) = &self.core.store {
+            store.clear()?;
         }
+        Ok(revoked)
     }
 
-    pub async fn call<K>(&self, service: Service<K>) -> Result<reqwest::Response, Error>
+    /// Make a request with Service.   
+    /// Returns `Err` if the server responds with a non-success status,
+    /// after the retries allowed by `ClientBuilder::retry()`.
+
+    pub async fn call<K>(&self, service: Service<K>) -> Result<Response, Error>
     where
         K: Serialize + Into<String> + PartialEq,
     {
-        let mut client = AsyncClient::new()
-            .request(service.method, service.url.as_str())
-            .query(&vec![("access_token", self.clone().token)]);
-        if let Some(params) = service.params {
-            client = client.query(&params);
+        let response = self.send(service).await?;
+        Ok(http::Response::from(response).into())
+    }
+
+    /// Drive a request of the Core through the transport.
+    async fn send<K>(&self, service: Service<K>) -> Result<HttpResponse, Error>
+    where
+        K: Serialize + Into<String> + PartialEq,
+    {
+        let mut call = self.core.call(&self.token, service)?;
+        let mut sent = None;
+        loop {
+            match call.step(sent.take()) {
+                Step::Send(request) => sent = Some(self.transport.send(request).await),
+                Step::Sleep(delay) => tokio::time::sleep(delay).await,
+                Step::Done(response) => return response,
+            }
+        }
+    }
+
+    /// Make a request with Service and decode the response to the type tied to its parameter.
+    ///
+    /// Examples
+    /// ========
+    /// ```rust
+    /// # use annis::Error;
+    /// # use annis::nonblocking::Client;
+    /// #
+    /// # async fn run() -> Result<(), Error> {
+    /// let client = Client::set_token("access_token");
+    /// let res = client.fetch(annis::works().params(vec![("filter_title", "lain")])).await?;
+    /// println!("{}", res.works[0].title);
+    /// #   Ok(())
+    /// # }
+    /// ```
+
+    pub async fn fetch<K>(&self, service: Service<K>) -> Result<K::Response, Error>
+    where
+        K: Serialize + Into<String> + PartialEq + Endpoint,
+    {
+        decode(&self.send(service).await?.text())
+    }
+
+    /// Make a stream which yields every item of a list endpoint, following `next_page`.
+    ///
+    /// Examples
+    /// ========
+    /// ```rust
+    /// # use annis::Error;
+    /// # use annis::nonblocking::Client;
+    /// use futures::TryStreamExt;
+    /// #
+    /// # async fn run() -> Result<(), Error> {
+    /// let client = Client::set_token("access_token");
+    /// let works = annis::works().params(vec![("filter_season", "2016-spring")]);
+    ///
+    /// let mut works = client.paginate(works).max_pages(3);
+    /// while let Some(work) = works.try_next().await? {
+    ///     println!("{}", work.title);
+    /// }
+    /// #   Ok(())
+    /// # }
+    /// ```
+
+    pub fn paginate<K>(&self, service: Service<K>) -> Paginator<K>
+    where
+        K: Serialize + Into<String> + PartialEq + Paginate + Clone,
+        K::Response: Page,
+    {
+        Paginator {
+            client: self.clone(),
+            cursor: Cursor::new(service.page()),
+            service,
+            pending: None,
+        }
+    }
+}
+
+/// A stream over the items of a list endpoint. Made by `Client::paginate()`.
+
+pub struct Paginator<K>
+where
+    K: Into<String> + PartialEq + Endpoint,
+    K::Response: Page,
+{
+    client: Client,
+    service: Service<K>,
+    cursor: Cursor<<K::Response as Page>::Item>,
+    pending: Option<BoxFuture<'static, Result<K::Response, Error>>>,
+}
+
+impl<K> Paginator<K>
+where
+    K: Into<String> + PartialEq + Endpoint,
+    K::Response: Page,
+{
+    /// Stop after yielding `max` items.
+    pub fn max_items(mut self, max: usize) -> Self {
+        self.cursor.max_items = Some(max);
+        self
+    }
+
+    /// Stop after requesting `max` pages.
+    pub fn max_pages(mut self, max: usize) -> Self {
+        self.cursor.max_pages = Some(max);
+        self
+    }
+}
+
+// No field is structurally pinned; the pending request is boxed.
+impl<K> Unpin for Paginator<K>
+where
+    K: Into<String> + PartialEq + Endpoint,
+    K::Response: Page,
+{
+}
+
+impl<K> Stream for Paginator<K>
+where
+    K: Serialize + Into<String> + PartialEq + Paginate + Clone + Send + 'static,
+    K::Response: Page + Send,
+{
+    type Item = Result<<K::Response as Page>::Item, Error>;
+
+    fn poll_next(self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<Option<Self::Item>> {
+        let this = self.get_mut();
+        loop {
+            if let Some(item) = this.cursor.next_item() {
+                return Poll::Ready(Some(Ok(item)));
+            }
+            if let Some(pending) = this.pending.as_mut() {
+                let result = match pending.as_mut().poll(cx) {
+                    Poll::Pending => return Poll::Pending,
+                    Poll::Ready(result) => result,
+                };
+                this.pending = None;
+                match result {
+                    Ok(response) => this.cursor.push(response),
+                    Err(e) => {
+                        this.cursor.finish();
+                        return Poll::Ready(Some(Err(e)));
+                    }
+                }
+                continue;
+            }
+            let page = match this.cursor.take_page() {
+                Some(page) => page,
+                None => return Poll::Ready(None),
+            };
+            let client = this.client.clone();
+            let service = this.service.with_page(page);
+            this.pending = Some(Box::pin(async move { client.fetch(service).await }));
+        }
+    }
+}
+
+// The token is left out so that logging a client doesn't leak it.
+impl fmt::Debug for Client {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.debug_struct("Client")
+            .field("token", &"<redacted>")
+            .field("core", &self.core)
+            .field("transport", &self.transport)
+            .finish()
+    }
+}
+
+impl Api for Client {
+    type Output<T: Send + 'static> = BoxFuture<'static, Result<T, Error>>;
+
+    fn fetch<K>(&self, service: Service<K>) -> Self::Output<K::Response>
+    where
+        K: Serialize + Into<String> + PartialEq + Endpoint + Send + 'static,
+        K::Response: Send + 'static,
+    {
+        let client = self.clone();
+        Box::pin(async move { client.fetch(service).await })
+    }
+
+    fn fetch_all<K>(&self, service: Service<K>) -> Self::Output<Vec<<K::Response as Page>::Item>>
+    where
+        K: Serialize + Into<String> + PartialEq + Paginate + Clone + Send + 'static,
+        K::Response: Page + Send + 'static,
+        <K::Response as Page>::Item: Send + 'static,
+    {
+        Box::pin(self.paginate(service).try_collect())
+    }
+
+    fn map<T, U, F>(output: Self::Output<T>, f: F) -> Self::Output<U>
+    where
+        T: Send + 'static,
+        U: Send + 'static,
+        F: FnOnce(T) -> U + Send + 'static,
+    {
+        Box::pin(async move { output.await.map(f) })
+    }
+}
+
+impl ClientBuilder {
+    /// Send requests with `transport` instead of `reqwest::Client`.
+    /// Settings of the HTTP client, e.g. `timeout()` or `proxy()`, are not used by it.
+    pub fn transport<T>(&mut self, transport: T) -> &mut Self
+    where
+        T: AsyncTransport + 'static,
+    {
+        self.async_transport = Some(Arc::new(transport));
+        self
+    }
+
+    /// # Panics
+    /// Panics if the HTTP client can't be initialized, e.g. with an invalid proxy URL or header.
+    /// Use `try_build()` to get the error instead.
+    pub fn build(&self) -> Client {
+        self.try_build().expect("failed to build the HTTP client")
+    }
+
+    pub fn try_build(&self) -> Result<Client, Error> {
+        let transport = match &self.async_transport {
+            Some(transport) => transport.clone(),
+            None => Arc::new(self.async_http()?),
         };
-        client.send().map_err(Into::into).await
+        Ok(Client {
+            token: self.token.clone(),
+            core: self.core(),
+            transport,
+        })
+    }
+
+    /// Build with the token saved in `store`, ignoring `token()`.   
+    /// Returns `ErrorKind::MissingToken` if the store is empty.
+    pub fn build_with_store<S>(&self, store: S) -> Result<Client, Error>
+    where
+        S: TokenStore + 'static,
+    {
+        let token = store.load()?.ok_or(ErrorKind::MissingToken)?;
+        let mut client = self.try_build()?;
+        client.token = token.access_token;
+        client.core.store = Some(Arc::new(store));
+        Ok(client)
     }
 }
@@ -0,0 +1,63 @@
+use crate::Page;
+use std::vec::IntoIter;
+
+/// Bookkeeping shared by `Paginator` and `nonblocking::Paginator`.
+
+#[derive(Debug)]
+pub(crate) struct Cursor<T> {
+    next_page: Option<u64>,
+    page: u64,
+    buffer: IntoIter<T>,
+    pages: usize,
+    items: usize,
+    pub(crate) max_pages: Option<usize>,
+    pub(crate) max_items: Option<usize>,
+}
+
+impl<T> Cursor<T> {
+    pub(crate) fn new(first_page: u64) -> Self {
+        Cursor {
+            next_page: Some(first_page),
+            page: first_page,
+            buffer: Vec::new().into_iter(),
+            pages: 0,
+            items: 0,
+            max_pages: None,
+            max_items: None,
+        }
+    }
+
+    /// Takes a buffered item, unless `max_items` has been reached.
+    pub(crate) fn next_item(&mut self) -> Option<T> {
+        if self.max_items.is_some_and(|max| self.items >= max) {
+            self.finish();
+            return None;
+        }
+        let item = self.buffer.next()?;
+        self.items += 1;
+        Some(item)
+    }
+
+    /// The page to request next, or `None` when pagination is over.
+    pub(crate) fn take_page(&mut self) -> Option<u64> {
+        if self.max_pages.is_some_and(|max| self.pages >= max) {
+            self.finish();
+        }
+        let page = self.next_page.take()?;
+        self.page = page;
+        Some(page)
+    }
+
+    /// Buffers the items of the page from `take_page()`.
+    /// A `next_page` which doesn't go forward ends pagination, so that it can't loop.
+    pub(crate) fn push<R: Page<Item = T>>(&mut self, response: R) {
+        self.pages += 1;
+        self.next_page = response.next_page().filter(|next| *next > self.page);
+        self.buffer = response.into_items().into_iter();
+    }
+
+    pub(crate) fn finish(&mut self) {
+        self.next_page = None;
+        self.buffer = Vec::new().into_iter();
+    }
+}
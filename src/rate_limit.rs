@@ -0,0 +1,174 @@
+use reqwest::header::{HeaderMap, RETRY_AFTER};
+use std::sync::{Arc, Mutex};
+use std::time::{Duration, Instant, SystemTime, UNIX_EPOCH};
+
+/// The longest pause taken from the headers of a response.
+const MAX_PAUSE: Duration = Duration::from_secs(60 * 60);
+
+/// A token bucket which spaces out the requests of clients.
+/// Set with `ClientBuilder::rate_limit()`.
+///
+/// The bucket holds up to `capacity` requests and refills at `capacity` per `per`.
+/// Clones share the bucket, so clients made with clones of one limiter share the budget too.
+///
+/// The limiter also reads `X-RateLimit-Limit`, `X-RateLimit-Remaining`, `X-RateLimit-Reset`
+/// and `Retry-After` of responses, and pauses when the server says the budget is spent,
+/// for an hour at most.
+///
+/// Examples
+/// ========
+/// ```rust
+/// # #[cfg(feature = "blocking")]
+/// # {
+/// # use annis::{Client, RateLimiter};
+/// use std::time::Duration;
+///
+/// let limiter = RateLimiter::new(60, Duration::from_secs(60));
+/// let client = Client::builder()
+///     .token("access_token")
+///     .rate_limit(limiter.clone())
+///     .build();
+///
+/// println!("{} requests left", limiter.budget().available);
+/// # }
+/// ```
+
+#[derive(Debug, Clone)]
+pub struct RateLimiter {
+    capacity: u32,
+    per: Duration,
+    state: Arc<Mutex<State>>,
+}
+
+/// A snapshot of the budget of a RateLimiter. Returned by `RateLimiter::budget()`.
+
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct RateBudget {
+    /// Requests which can be sent now without waiting.
+    pub available: u32,
+    pub capacity: u32,
+    /// `X-RateLimit-Limit` of the last response which had it.
+    pub limit: Option<u64>,
+    /// `X-RateLimit-Remaining` of the last response which had it.
+    pub remaining: Option<u64>,
+    /// How long requests are paused for by the server.
+    pub paused_for: Option<Duration>,
+}
+
+#[derive(Debug)]
+struct State {
+    tokens: f64,
+    refilled_at: Instant,
+    paused_until: Option<Instant>,
+    limit: Option<u64>,
+    remaining: Option<u64>,
+}
+
+impl RateLimiter {
+    /// A bucket of `capacity` requests which refills at `capacity` per `per`.
+    pub fn new(capacity: u32, per: Duration) -> Self {
+        let capacity = capacity.max(1);
+        RateLimiter {
+            capacity,
+            per,
+            state: Arc::new(Mutex::new(State {
+                tokens: f64::from(capacity),
+                refilled_at: Instant::now(),
+                paused_until: None,
+                limit: None,
+                remaining: None,
+            })),
+        }
+    }
+
+    pub fn budget(&self) -> RateBudget {
+        let now = Instant::now();
+        let mut state = self.lock();
+        self.refill(&mut state, now);
+        RateBudget {
+            available: state.tokens.max(0.0) as u32,
+            capacity: self.capacity,
+            limit: state.limit,
+            remaining: state.remaining,
+            paused_for: state
+                .paused_until
+                .filter(|until| *until > now)
+                .map(|until| until - now),
+        }
+    }
+
+    /// Takes a request from the bucket, and returns how long to wait before sending it.
+    pub(crate) fn acquire(&self) -> Duration {
+        let now = Instant::now();
+        let mut state = self.lock();
+        self.refill(&mut state, now);
+        // Going below zero makes later callers wait behind this one.
+        state.tokens -= 1.0;
+        let refill_wait = if state.tokens >= 0.0 {
+            Duration::ZERO
+        } else {
+            self.per.mul_f64(-state.tokens / f64::from(self.capacity))
+        };
+        let pause_wait = state
+            .paused_until
+            .filter(|until| *until > now)
+            .map_or(Duration::ZERO, |until| until - now);
+        refill_wait.max(pause_wait)
+    }
+
+    /// Reads the rate limit headers of a response.
+    pub(crate) fn update(&self, headers: &HeaderMap) {
+        let header = |name: &str| {
+            headers
+                .get(name)
+                .and_then(|v| v.to_str().ok())
+                .and_then(|v| v.trim().parse::<u64>().ok())
+        };
+        let now = Instant::now();
+        let mut state = self.lock();
+        if let Some(limit) = header("x-ratelimit-limit") {
+            state.limit = Some(limit);
+        }
+        let mut pause = None;
+        if let Some(remaining) = header("x-ratelimit-remaining") {
+            state.remaining = Some(remaining);
+            if remaining == 0 {
+                pause = header("x-ratelimit-reset").map(until_reset);
+            }
+        }
+        if let Some(seconds) = header(RETRY_AFTER.as_str()) {
+            pause = pause.max(Some(Duration::from_secs(seconds)));
+        }
+        if let Some(until) = pause.and_then(|pause| now.checked_add(pause.min(MAX_PAUSE))) {
+            state.paused_until = state.paused_until.max(Some(until));
+        }
+    }
+
+    fn lock(&self) -> std::sync::MutexGuard<'_, State> {
+        self.state.lock().unwrap_or_else(|e| e.into_inner())
+    }
+
+    fn refill(&self, state: &mut State, now: Instant) {
+        let elapsed = now.saturating_duration_since(state.refilled_at);
+        let refilled = if self.per.is_zero() {
+            f64::from(self.capacity)
+        } else {
+            elapsed.as_secs_f64() / self.per.as_secs_f64() * f64::from(self.capacity)
+        };
+        state.tokens = (state.tokens + refilled).min(f64::from(self.capacity));
+        state.refilled_at = now;
+    }
+}
+
+/// `X-RateLimit-Reset` is either a UNIX time or seconds from now.
+fn until_reset(reset: u64) -> Duration {
+    const UNIX_TIME: u64 = 1_000_000_000;
+    if reset < UNIX_TIME {
+        return Duration::from_secs(reset);
+    }
+    let now = SystemTime::now()
+        .duration_since(UNIX_EPOCH)
+        .map(|d| d.as_secs())
+        .unwrap_or_default();
+    Duration::from_secs(reset.saturating_sub(now))
+}
@@ -0,0 +1,54 @@
+use crate::{Error, ErrorKind};
+use serde::{Deserialize, Serialize};
+use std::fmt;
+use std::str::FromStr;
+
+/// A rating of a record or a review.
+/// A value of `MeRecords::rating_state` and the `MeReviews::rating_*_state` keys.
+/// Records also accept the legacy numeric `MeRecords::rating`, from 0.0 to 5.0.
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, PartialOrd, Ord, Serialize, Deserialize)]
+#[serde(rename_all = "lowercase")]
+pub enum RatingState {
+    Bad,
+    Average,
+    Good,
+    Great,
+}
+
+impl RatingState {
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            RatingState::Bad => "bad",
+            RatingState::Average => "average",
+            RatingState::Good => "good",
+            RatingState::Great => "great",
+        }
+    }
+}
+
+impl fmt::Display for RatingState {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "{}", self.as_str())
+    }
+}
+
+impl FromStr for RatingState {
+    type Err = Error;
+
+    fn from_str(s: &str) -> Result<Self, Error> {
+        match s {
+            "bad" => Ok(RatingState::Bad),
+            "average" => Ok(RatingState::Average),
+            "good" => Ok(RatingState::Good),
+            "great" => Ok(RatingState::Great),
+            s => Err(ErrorKind::InvalidParameter(format!("unknown rating state {}", s)).into()),
+        }
+    }
+}
+
+impl From<RatingState> for String {
+    fn from(state: RatingState) -> String {
+        state.as_str().to_string()
+    }
+}
@@ -0,0 +1,185 @@
+use crate::{Error, ErrorKind};
+use reqwest::{Method, StatusCode};
+use std::time::Duration;
+
+/// When and how long to wait before a failed request is sent again.
+/// Set with `ClientBuilder::retry()`. Clients don't retry unless it is set.
+///
+/// The delay doubles after each attempt from `base_delay` up to `max_delay`,
+/// and jitter picks a random delay between half and all of it.
+/// A `Retry-After` header of 429 responses is waited instead when present,
+/// and a `Retry-After` longer than `max_delay` returns the error without retrying.
+///
+/// POST and PATCH requests aren't idempotent, so they are never retried
+/// unless `retry_non_idempotent(true)` is set.
+///
+/// Examples
+/// ========
+/// ```rust
+/// # #[cfg(feature = "blocking")]
+/// # {
+/// # use annis::{Client, RetryPolicy};
+/// use std::time::Duration;
+///
+/// let client = Client::builder()
+///     .token("access_token")
+///     .retry(
+///         RetryPolicy::new()
+///             .max_attempts(5)
+///             .base_delay(Duration::from_millis(200)),
+///     )
+///     .build();
+/// # }
+/// ```
+
+#[derive(Debug, Clone, PartialEq)]
+pub struct RetryPolicy {
+    max_attempts: u32,
+    base_delay: Duration,
+    max_delay: Duration,
+    jitter: bool,
+    statuses: Vec<StatusCode>,
+    network: bool,
+    timeout: bool,
+    non_idempotent: bool,
+}
+
+impl Default for RetryPolicy {
+    fn default() -> Self {
+        RetryPolicy {
+            max_attempts: 3,
+            base_delay: Duration::from_millis(500),
+            max_delay: Duration::from_secs(30),
+            jitter: true,
+            statuses: vec![
+                StatusCode::TOO_MANY_REQUESTS,
+                StatusCode::INTERNAL_SERVER_ERROR,
+                StatusCode::BAD_GATEWAY,
+                StatusCode::SERVICE_UNAVAILABLE,
+                StatusCode::GATEWAY_TIMEOUT,
+            ],
+            network: true,
+            timeout: true,
+            non_idempotent: false,
+        }
+    }
+}
+
+impl RetryPolicy {
+    /// 3 attempts from a delay of 500ms, on network errors, timeouts
+    /// and 429, 500, 502, 503 and 504 statuses.
+    pub fn new() -> Self {
+        RetryPolicy::default()
+    }
+
+    /// A policy which sends every request once. The default of clients.
+    pub fn never() -> Self {
+        RetryPolicy::default().max_attempts(1)
+    }
+
+    /// How many times a request is sent at most, including the first time.
+    pub fn max_attempts(self, max_attempts: u32) -> Self {
+        RetryPolicy {
+            max_attempts: max_attempts.max(1),
+            ..self
+        }
+    }
+
+    /// The delay before the first retry.
+    pub fn base_delay(self, base_delay: Duration) -> Self {
+        RetryPolicy { base_delay, ..self }
+    }
+
+    /// The longest delay between attempts. A longer `Retry-After` of the server isn't waited.
+    pub fn max_delay(self, max_delay: Duration) -> Self {
+        RetryPolicy { max_delay, ..self }
+    }
+
+    pub fn jitter(self, jitter: bool) -> Self {
+        RetryPolicy { jitter, ..self }
+    }
+
+    /// Retry on these statuses, instead of 429, 500, 502, 503 and 504.
+    pub fn statuses<I>(self, statuses: I) -> Self
+    where
+        I: IntoIterator<Item = StatusCode>,
+    {
+        RetryPolicy {
+            statuses: statuses.into_iter().collect(),
+            ..self
+        }
+    }
+
+    /// Retry when the connection fails or is lost.
+    pub fn retry_network(self, network: bool) -> Self {
+        RetryPolicy { network, ..self }
+    }
+
+    /// Retry when the request times out.
+    pub fn retry_timeout(self, timeout: bool) -> Self {
+        RetryPolicy { timeout, ..self }
+    }
+
+    /// Retry POST and PATCH requests too, which may be applied twice by the server.
+    pub fn retry_non_idempotent(self, non_idempotent: bool) -> Self {
+        RetryPolicy {
+            non_idempotent,
+            ..self
+        }
+    }
+
+    /// The delay before the `attempt`th retry, counted from 1, without jitter.
+    pub fn backoff(&self, attempt: u32) -> Duration {
+        let factor = 2u32.saturating_pow(attempt.saturating_sub(1));
+        self.base_delay
+            .checked_mul(factor)
+            .map_or(self.max_delay, |delay| delay.min(self.max_delay))
+    }
+
+    /// The delay before sending again a request which failed with `error` on
+    /// its `attempt`th try, or `None` if it shouldn't be sent again.
+    pub(crate) fn retry_delay(
+        &self,
+        method: &Method,
+        attempt: u32,
+        error: &Error,
+    ) -> Option<Duration> {
+        if attempt >= self.max_attempts || !(self.non_idempotent || is_idempotent(method)) {
+            return None;
+        }
+        let retryable = match error.kind() {
+            ErrorKind::Network => self.network,
+            ErrorKind::Timeout => self.timeout,
+            _ => error.status().is_some_and(|s| self.statuses.contains(&s)),
+        };
+        if !retryable {
+            return None;
+        }
+        if let ErrorKind::RateLimited {
+            retry_after: Some(retry_after),
+            ..
+        } = error.kind()
+        {
+            return Some(*retry_after).filter(|retry_after| *retry_after <= self.max_delay);
+        }
+        let delay = self.backoff(attempt);
+        if self.jitter {
+            Some(delay / 2 + delay.mul_f64(random_fraction() / 2.0))
+        } else {
+            Some(delay)
+        }
+    }
+}
+
+fn is_idempotent(method: &Method) -> bool {
+    !matches!(*method, Method::POST | Method::PATCH)
+}
+
+/// A random number in [0, 1).
+fn random_fraction() -> f64 {
+    use std::collections::hash_map::RandomState;
+    use std::hash::{BuildHasher, Hasher};
+
+    let hash = RandomState::new().build_hasher().finish();
+    (hash >> 11) as f64 / (1u64 << 53) as f64
+}
@@ -0,0 +1,148 @@
+use crate::{me_reviews, Error, ErrorKind, MeReviews, Method, RatingState, Service};
+
+/// A review to post or edit with /v1/me/reviews.
+/// Made into a Service by `create()` or `update()`, which require a non-empty `body`.
+///
+/// Examples
+/// ========
+/// ```rust
+/// # #[cfg(feature = "blocking")]
+/// # {
+/// # use annis::{Client, Error, RatingState, ReviewDraft};
+/// #
+/// # fn run() -> Result<(), Error> {
+/// let client = Client::set_token("annict_access_token");
+///
+/// let review = ReviewDraft::new("Present day, present time.")
+///     .title("Lain")
+///     .story(RatingState::Great)
+///     .overall(RatingState::Great);
+///
+/// let posted = client.fetch(review.create(860)?)?;
+/// # Ok(())
+/// # }
+/// # }
+/// ```
+
+#[derive(Debug, Clone, Default, PartialEq)]
+pub struct ReviewDraft {
+    pub title: Option<String>,
+    pub body: String,
+    pub animation: Option<RatingState>,
+    pub music: Option<RatingState>,
+    pub story: Option<RatingState>,
+    pub character: Option<RatingState>,
+    pub overall: Option<RatingState>,
+    pub share_twitter: bool,
+    pub share_facebook: bool,
+}
+
+impl ReviewDraft {
+    pub fn new<P>(body: P) -> Self
+    where
+        P: Into<String>,
+    {
+        ReviewDraft {
+            body: body.into(),
+            ..ReviewDraft::default()
+        }
+    }
+
+    pub fn title<P>(self, title: P) -> Self
+    where
+        P: Into<String>,
+    {
+        ReviewDraft {
+            title: Some(title.into()),
+            ..self
+        }
+    }
+
+    pub fn animation(self, state: RatingState) -> Self {
+        ReviewDraft {
+            animation: Some(state),
+            ..self
+        }
+    }
+
+    pub fn music(self, state: RatingState) -> Self {
+        ReviewDraft {
+            music: Some(state),
+            ..self
+        }
+    }
+
+    pub fn story(self, state: RatingState) -> Self {
+        ReviewDraft {
+            story: Some(state),
+            ..self
+        }
+    }
+
+    pub fn character(self, state: RatingState) -> Self {
+        ReviewDraft {
+            character: Some(state),
+            ..self
+        }
+    }
+
+    pub fn overall(self, state: RatingState) -> Self {
+        ReviewDraft {
+            overall: Some(state),
+            ..self
+        }
+    }
+
+    pub fn share_twitter(self, share: bool) -> Self {
+        ReviewDraft {
+            share_twitter: share,
+            ..self
+        }
+    }
+
+    pub fn share_facebook(self, share: bool) -> Self {
+        ReviewDraft {
+            share_facebook: share,
+            ..self
+        }
+    }
+
+    /// POST a new review of the work.
+    pub fn create(&self, work_id: usize) -> Result<Service<MeReviews>, Error> {
+        Ok(me_reviews(Method::POST, work_id).params(self.params()?))
+    }
+
+    /// PATCH the review.
+    pub fn update(&self, review_id: usize) -> Result<Service<MeReviews>, Error> {
+        Ok(me_reviews(Method::PATCH, review_id).params(self.params()?))
+    }
+
+    fn params(&self) -> Result<Vec<(MeReviews, String)>, Error> {
+        if self.body.trim().is_empty() {
+            return Err(ErrorKind::InvalidParameter("review body is empty".into()).into());
+        }
+        let mut params = vec![(MeReviews::body, self.body.clone())];
+        if let Some(title) = &self.title {
+            params.push((MeReviews::title, title.clone()));
+        }
+        let states = [
+            (MeReviews::rating_animation_state, self.animation),
+            (MeReviews::rating_music_state, self.music),
+            (MeReviews::rating_story_state, self.story),
+            (MeReviews::rating_character_state, self.character),
+            (MeReviews::rating_overall_state, self.overall),
+        ];
+        for (key, state) in states.iter() {
+            if let Some(state) = state {
+                params.push((key.clone(), state.to_string()));
+            }
+        }
+        if self.share_twitter {
+            params.push((MeReviews::share_twitter, "true".to_string()));
+        }
+        if self.share_facebook {
+            params.push((MeReviews::share_facebook, "true".to_string()));
+        }
+        Ok(params)
+    }
+}
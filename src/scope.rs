@@ -0,0 +1,170 @@
+use crate::{Error, ErrorKind};
+use serde::{Deserialize, Deserializer, Serialize, Serializer};
+use std::fmt;
+use std::ops::BitOr;
+use std::str::FromStr;
+
+/// A scope of Annict access tokens.
+///
+/// Examples
+/// ========
+/// ```rust
+/// # use annis::{OAuth, Scope};
+/// let url = OAuth::client_id("client_id")
+///     .authorize_url()
+///     .scope(Scope::Read | Scope::Write)
+///     .build();
+/// ```
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Serialize, Deserialize)]
+#[serde(rename_all = "lowercase")]
+pub enum Scope {
+    Read,
+    Write,
+}
+
+/// A set of `Scope`. Made with `|`, e.g. `Scope::Read | Scope::Write`.
+/// Formatted and parsed as the space-separated form of OAuth, e.g. `read write`.
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Default)]
+pub struct Scopes(u8);
+
+impl Scope {
+    const ALL: [Scope; 2] = [Scope::Read, Scope::Write];
+
+    fn bit(self) -> u8 {
+        match self {
+            Scope::Read => 1,
+            Scope::Write => 1 << 1,
+        }
+    }
+}
+
+impl Scopes {
+    pub fn new() -> Self {
+        Scopes(0)
+    }
+
+    pub fn contains(&self, scope: Scope) -> bool {
+        self.0 & scope.bit() != 0
+    }
+
+    pub fn insert(&mut self, scope: Scope) -> &mut Self {
+        self.0 |= scope.bit();
+        self
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.0 == 0
+    }
+
+    pub fn iter(&self) -> impl Iterator<Item = Scope> + '_ {
+        Scope::ALL.iter().copied().filter(move |s| self.contains(*s))
+    }
+}
+
+impl fmt::Display for Scope {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            Scope::Read => write!(f, "read"),
+            Scope::Write => write!(f, "write"),
+        }
+    }
+}
+
+impl fmt::Display for Scopes {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        let scopes: Vec<String> = self.iter().map(|s| s.to_string()).collect();
+        write!(f, "{}", scopes.join(" "))
+    }
+}
+
+impl FromStr for Scope {
+    type Err = Error;
+
+    fn from_str(s: &str) -> Result<Self, Error> {
+        match s {
+            "read" => Ok(Scope::Read),
+            "write" => Ok(Scope::Write),
+            s => Err(ErrorKind::InvalidParameter(format!("unknown scope {}", s)).into()),
+        }
+    }
+}
+
+/// Accepts `read write`, `read+write` and `read,write`.
+impl FromStr for Scopes {
+    type Err = Error;
+
+    fn from_str(s: &str) -> Result<Self, Error> {
+        s.split(|c: char| c.is_whitespace() || c == '+' || c == ',')
+            .filter(|s| !s.is_empty())
+            .map(str::parse)
+            .collect()
+    }
+}
+
+impl From<Scope> for Scopes {
+    fn from(scope: Scope) -> Scopes {
+        Scopes(scope.bit())
+    }
+}
+
+impl From<Scope> for String {
+    fn from(scope: Scope) -> String {
+        scope.to_string()
+    }
+}
+
+impl From<Scopes> for String {
+    fn from(scopes: Scopes) -> String {
+        scopes.to_string()
+    }
+}
+
+impl std::iter::FromIterator<Scope> for Scopes {
+    fn from_iter<I: IntoIterator<Item = Scope>>(iter: I) -> Self {
+        let mut scopes = Scopes::new();
+        for scope in iter {
+            scopes.insert(scope);
+        }
+        scopes
+    }
+}
+
+impl BitOr for Scope {
+    type Output = Scopes;
+
+    fn bitor(self, rhs: Scope) -> Scopes {
+        Scopes(self.bit() | rhs.bit())
+    }
+}
+
+impl BitOr<Scope> for Scopes {
+    type Output = Scopes;
+
+    fn bitor(self, rhs: Scope) -> Scopes {
+        Scopes(self.0 | rhs.bit())
+    }
+}
+
+impl BitOr for Scopes {
+    type Output = Scopes;
+
+    fn bitor(self, rhs: Scopes) -> Scopes {
+        Scopes(self.0 | rhs.0)
+    }
+}
+
+impl Serialize for Scopes {
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        serializer.collect_str(self)
+    }
+}
+
+impl<'de> Deserialize<'de> for Scopes {
+    fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        String::deserialize(deserializer)?
+            .parse()
+            .map_err(serde::de::Error::custom)
+    }
+}
@@ -0,0 +1,221 @@
+use crate::{Error, ErrorKind};
+use serde::{Deserialize, Deserializer, Serialize, Serializer};
+use std::fmt;
+use std::ops::RangeInclusive;
+use std::str::FromStr;
+use std::time::{SystemTime, UNIX_EPOCH};
+
+/// A broadcast season, e.g. `2024-spring`, or a whole year, e.g. `2024-all`.
+/// A value of `Works::filter_season` and `MeWorks::filter_season`.
+///
+/// Examples
+/// ========
+/// ```rust
+/// # use annis::{Quarter, Season, Works};
+/// let spring = Season::new(2024, Quarter::Spring);
+/// assert_eq!(spring.to_string(), "2024-spring");
+/// assert_eq!(spring.next(), "2024-summer".parse().ok());
+///
+/// let works = annis::works().params(vec![(Works::filter_season, spring)]);
+///
+/// for season in Season::range(Season::new(2020, Quarter::Winter)..=Season::new(2021, Quarter::Autumn)) {
+///     println!("{}", season);
+/// }
+/// ```
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, PartialOrd, Ord)]
+pub struct Season {
+    pub year: u32,
+    /// `None` for the whole year.
+    pub quarter: Option<Quarter>,
+}
+
+/// A quarter of a year, in the order of Annict: winter is January to March.
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, PartialOrd, Ord)]
+pub enum Quarter {
+    Winter,
+    Spring,
+    Summer,
+    Autumn,
+}
+
+/// An iterator over the seasons of a range. Made by `Season::range()`.
+
+#[derive(Debug, Clone)]
+pub struct SeasonRange {
+    next: Option<Season>,
+    end: Season,
+}
+
+impl Season {
+    pub fn new(year: u32, quarter: Quarter) -> Self {
+        Season {
+            year,
+            quarter: Some(quarter),
+        }
+    }
+
+    /// The whole year, formatted as `<year>-all`.
+    pub fn all(year: u32) -> Self {
+        Season {
+            year,
+            quarter: None,
+        }
+    }
+
+    /// The season of today in Japan Standard Time.
+    pub fn current() -> Self {
+        let secs = SystemTime::now()
+            .duration_since(UNIX_EPOCH)
+            .map(|d| d.as_secs())
+            .unwrap_or_default();
+        let (year, month) = year_month((secs + 9 * 60 * 60) / 86400);
+        Season::new(year, Quarter::of_month(month))
+    }
+
+    /// The season after this one, or the next year for a whole year.
+    /// `None` past the year `u32::MAX`.
+    pub fn next(&self) -> Option<Self> {
+        match self.quarter {
+            Some(Quarter::Autumn) => Some(Season::new(self.year.checked_add(1)?, Quarter::Winter)),
+            Some(quarter) => Some(Season::new(self.year, Quarter::ALL[quarter as usize + 1])),
+            None => Some(Season::all(self.year.checked_add(1)?)),
+        }
+    }
+
+    /// The season before this one, or the previous year for a whole year.
+    /// `None` before the year 0.
+    pub fn prev(&self) -> Option<Self> {
+        match self.quarter {
+            Some(Quarter::Winter) => Some(Season::new(self.year.checked_sub(1)?, Quarter::Autumn)),
+            Some(quarter) => Some(Season::new(self.year, Quarter::ALL[quarter as usize - 1])),
+            None => Some(Season::all(self.year.checked_sub(1)?)),
+        }
+    }
+
+    /// Every season from the start to the end of `range`.
+    /// A whole year at the start begins with its winter, and one at the end ends with its autumn.
+    pub fn range(range: RangeInclusive<Season>) -> SeasonRange {
+        let (start, end) = range.into_inner();
+        let start = Season::new(start.year, start.quarter.unwrap_or(Quarter::Winter));
+        let end = Season::new(end.year, end.quarter.unwrap_or(Quarter::Autumn));
+        SeasonRange {
+            next: Some(start).filter(|start| *start <= end),
+            end,
+        }
+    }
+}
+
+impl Quarter {
+    const ALL: [Quarter; 4] = [
+        Quarter::Winter,
+        Quarter::Spring,
+        Quarter::Summer,
+        Quarter::Autumn,
+    ];
+
+    /// The quarter of a month from 1 to 12.
+    pub fn of_month(month: u32) -> Self {
+        Quarter::ALL[(month.clamp(1, 12) as usize - 1) / 3]
+    }
+}
+
+impl Iterator for SeasonRange {
+    type Item = Season;
+
+    fn next(&mut self) -> Option<Season> {
+        let season = self.next?;
+        self.next = season.next().filter(|next| *next <= self.end);
+        Some(season)
+    }
+}
+
+impl fmt::Display for Quarter {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            Quarter::Winter => write!(f, "winter"),
+            Quarter::Spring => write!(f, "spring"),
+            Quarter::Summer => write!(f, "summer"),
+            Quarter::Autumn => write!(f, "autumn"),
+        }
+    }
+}
+
+impl fmt::Display for Season {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self.quarter {
+            Some(quarter) => write!(f, "{}-{}", self.year, quarter),
+            None => write!(f, "{}-all", self.year),
+        }
+    }
+}
+
+impl FromStr for Quarter {
+    type Err = Error;
+
+    fn from_str(s: &str) -> Result<Self, Error> {
+        match s {
+            "winter" => Ok(Quarter::Winter),
+            "spring" => Ok(Quarter::Spring),
+            "summer" => Ok(Quarter::Summer),
+            "autumn" | "fall" => Ok(Quarter::Autumn),
+            s => Err(ErrorKind::InvalidParameter(format!("unknown quarter {}", s)).into()),
+        }
+    }
+}
+
+/// Accepts `<year>-<quarter>` and `<year>-all`.
+impl FromStr for Season {
+    type Err = Error;
+
+    fn from_str(s: &str) -> Result<Self, Error> {
+        let invalid = || ErrorKind::InvalidParameter(format!("invalid season {}", s));
+        let (year, quarter) = s.split_once('-').ok_or_else(invalid)?;
+        let year = year.parse().map_err(|_| invalid())?;
+        match quarter {
+            "all" => Ok(Season::all(year)),
+            quarter => Ok(Season::new(year, quarter.parse().map_err(|_| invalid())?)),
+        }
+    }
+}
+
+impl From<Season> for String {
+    fn from(season: Season) -> String {
+        season.to_string()
+    }
+}
+
+impl From<Quarter> for String {
+    fn from(quarter: Quarter) -> String {
+        quarter.to_string()
+    }
+}
+
+impl Serialize for Season {
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        serializer.collect_str(self)
+    }
+}
+
+impl<'de> Deserialize<'de> for Season {
+    fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        String::deserialize(deserializer)?
+            .parse()
+            .map_err(serde::de::Error::custom)
+    }
+}
+
+/// The year and month of a day counted from 1970-01-01.
+fn year_month(days: u64) -> (u32, u32) {
+    // http://howardhinnant.github.io/date_algorithms.html#civil_from_days
+    let z = days + 719_468;
+    let era = z / 146_097;
+    let doe = z % 146_097;
+    let yoe = (doe - doe / 1460 + doe / 36_524 - doe / 146_096) / 365;
+    let doy = doe - (365 * yoe + yoe / 4 - yoe / 100);
+    let mp = (5 * doy + 2) / 153;
+    let month = if mp < 10 { mp + 3 } else { mp - 9 };
+    let year = yoe + era * 400 + u64::from(month <= 2);
+    (year as u32, month as u32)
+}
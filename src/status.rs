@@ -0,0 +1,79 @@
+use crate::{Error, ErrorKind};
+use serde::{Deserialize, Serialize};
+use std::fmt;
+use std::str::FromStr;
+
+/// A watching status of a work.
+/// A value of `MeStatuses::kind` and `MeWorks::filter_status`, and `models::Status::kind`.
+///
+/// Examples
+/// ========
+/// ```rust
+/// # #[cfg(feature = "blocking")]
+/// # {
+/// # use annis::{Client, Error, MeWorks, WatchStatus};
+/// #
+/// # fn run() -> Result<(), Error> {
+/// let client = Client::set_token("annict_access_token");
+///
+/// client.call(annis::me_statuses_set(3994, WatchStatus::Watched))?;
+///
+/// let watching = annis::me_works().params(vec![(MeWorks::filter_status, WatchStatus::Watching)]);
+/// client.fetch(watching)?;
+/// # Ok(())
+/// # }
+/// # }
+/// ```
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum WatchStatus {
+    WannaWatch,
+    Watching,
+    Watched,
+    OnHold,
+    StopWatching,
+    /// Clears the status.
+    NoSelect,
+}
+
+impl WatchStatus {
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            WatchStatus::WannaWatch => "wanna_watch",
+            WatchStatus::Watching => "watching",
+            WatchStatus::Watched => "watched",
+            WatchStatus::OnHold => "on_hold",
+            WatchStatus::StopWatching => "stop_watching",
+            WatchStatus::NoSelect => "no_select",
+        }
+    }
+}
+
+impl fmt::Display for WatchStatus {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "{}", self.as_str())
+    }
+}
+
+impl FromStr for WatchStatus {
+    type Err = Error;
+
+    fn from_str(s: &str) -> Result<Self, Error> {
+        match s {
+            "wanna_watch" => Ok(WatchStatus::WannaWatch),
+            "watching" => Ok(WatchStatus::Watching),
+            "watched" => Ok(WatchStatus::Watched),
+            "on_hold" => Ok(WatchStatus::OnHold),
+            "stop_watching" => Ok(WatchStatus::StopWatching),
+            "no_select" => Ok(WatchStatus::NoSelect),
+            s => Err(ErrorKind::InvalidParameter(format!("unknown status {}", s)).into()),
+        }
+    }
+}
+
+impl From<WatchStatus> for String {
+    fn from(status: WatchStatus) -> String {
+        status.as_str().to_string()
+    }
+}
@@ -0,0 +1,144 @@
+use crate::models::TokenResponse;
+use crate::Error;
+use std::fmt::Debug;
+use std::fs;
+use std::io::{ErrorKind as IoErrorKind, Write};
+use std::path::{Path, PathBuf};
+use std::sync::Mutex;
+
+/// A place to keep the access token between runs.
+/// Used by `Client::from_store()` and `AccessToken::build_and_save()`.
+
+pub trait TokenStore: Debug + Send + Sync {
+    fn load(&self) -> Result<Option<TokenResponse>, Error>;
+    fn save(&self, token: &TokenResponse) -> Result<(), Error>;
+    fn clear(&self) -> Result<(), Error>;
+}
+
+/// A TokenStore which writes the token to a JSON file readable only by the owner.
+///
+/// Examples
+/// ========
+/// ```rust
+/// # #[cfg(feature = "blocking")]
+/// # {
+/// # use annis::{Client, Error, FileTokenStore, OAuth};
+/// #
+/// # fn run() -> Result<(), Error> {
+/// let store = FileTokenStore::new("/home/user/.config/app/annict.json");
+///
+/// OAuth::client_id("client_id")
+///     .access_token()
+///     .client_secret("client_secret_key")
+///     .code("certification code")
+///     .build_and_save(&store)?;
+///
+/// // -> On the next run.
+/// let client = Client::from_store(store)?;
+/// # Ok(())
+/// # }
+/// # }
+/// ```
+
+#[derive(Debug, Clone)]
+pub struct FileTokenStore {
+    path: PathBuf,
+}
+
+/// A TokenStore which keeps the token in memory.
+
+#[derive(Debug, Default)]
+pub struct MemoryTokenStore {
+    token: Mutex<Option<TokenResponse>>,
+}
+
+impl FileTokenStore {
+    pub fn new<P: Into<PathBuf>>(path: P) -> Self {
+        FileTokenStore { path: path.into() }
+    }
+
+    pub fn path(&self) -> &Path {
+        &self.path
+    }
+}
+
+impl TokenStore for FileTokenStore {
+    fn load(&self) -> Result<Option<TokenResponse>, Error> {
+        match fs::read(&self.path) {
+            Ok(json) => Ok(Some(serde_json::from_slice(&json)?)),
+            Err(e) if e.kind() == IoErrorKind::NotFound => Ok(None),
+            Err(e) => Err(e.into()),
+        }
+    }
+
+    /// Writes to a new temporary file next to `path`, then renames it over `path`.
+    fn save(&self, token: &TokenResponse) -> Result<(), Error> {
+        if let Some(dir) = self.path.parent().filter(|dir| !dir.as_os_str().is_empty()) {
+            fs::create_dir_all(dir)?;
+        }
+        replace_file(&self.path, &serde_json::to_vec(token)?)?;
+        Ok(())
+    }
+
+    fn clear(&self) -> Result<(), Error> {
+        match fs::remove_file(&self.path) {
+            Err(e) if e.kind() != IoErrorKind::NotFound => Err(e.into()),
+            _ => Ok(()),
+        }
+    }
+}
+
+/// Replaces `path` with `contents` readable only by the owner.
+/// The temporary file has a random name and must not exist yet,
+/// so that concurrent writers or a file planted under that name can't be written through.
+
+pub(crate) fn replace_file(path: &Path, contents: &[u8]) -> std::io::Result<()> {
+    let mut tmp = path.as_os_str().to_os_string();
+    tmp.push(format!(".{}.tmp", crate::random_state()));
+    let tmp = PathBuf::from(tmp);
+
+    let mut options = fs::OpenOptions::new();
+    options.write(true).create_new(true);
+    #[cfg(unix)]
+    {
+        use std::os::unix::fs::OpenOptionsExt;
+        options.mode(0o600);
+    }
+    let mut file = options.open(&tmp)?;
+    let written = (|| {
+        #[cfg(unix)]
+        {
+            use std::os::unix::fs::PermissionsExt;
+            file.set_permissions(fs::Permissions::from_mode(0o600))?;
+        }
+        file.write_all(contents)?;
+        file.sync_all()?;
+        fs::rename(&tmp, path)
+    })();
+    if written.is_err() {
+        let _ = fs::remove_file(&tmp);
+    }
+    written
+}
+
+impl MemoryTokenStore {
+    pub fn new() -> Self {
+        MemoryTokenStore::default()
+    }
+}
+
+impl TokenStore for MemoryTokenStore {
+    fn load(&self) -> Result<Option<TokenResponse>, Error> {
+        Ok(self.token.lock().unwrap_or_else(|e| e.into_inner()).clone())
+    }
+
+    fn save(&self, token: &TokenResponse) -> Result<(), Error> {
+        *self.token.lock().unwrap_or_else(|e| e.into_inner()) = Some(token.clone());
+        Ok(())
+    }
+
+    fn clear(&self) -> Result<(), Error> {
+        *self.token.lock().unwrap_or_else(|e| e.into_inner()) = None;
+        Ok(())
+    }
+}
@@ -0,0 +1,126 @@
+use crate::Error;
+#[cfg(feature = "async")]
+use futures::future::BoxFuture;
+use reqwest::header::HeaderMap;
+use reqwest::{Method, StatusCode, Url};
+use std::fmt::Debug;
+
+/// A request made by a client, ready to be sent by a Transport.
+
+#[derive(Debug, Clone)]
+pub struct HttpRequest {
+    pub method: Method,
+    pub url: Url,
+    pub headers: HeaderMap,
+    pub body: Option<Vec<u8>>,
+}
+
+/// A response read to the end by a Transport.
+
+#[derive(Debug, Clone)]
+pub struct HttpResponse {
+    pub status: StatusCode,
+    pub headers: HeaderMap,
+    pub body: Vec<u8>,
+}
+
+/// What sends the requests of `Client`. Set with `ClientBuilder::transport()`.
+/// `reqwest::blocking::Client` is the default, and others can be plugged in,
+/// e.g. one which returns recorded responses in tests.
+///
+/// Examples
+/// ========
+/// ```rust
+/// # #[cfg(feature = "blocking")]
+/// # {
+/// # use annis::{Client, Error, HttpRequest, HttpResponse, Transport};
+/// use reqwest::header::HeaderMap;
+/// use reqwest::StatusCode;
+///
+/// #[derive(Debug)]
+/// struct Recorded;
+///
+/// impl Transport for Recorded {
+///     fn send(&self, request: HttpRequest) -> Result<HttpResponse, Error> {
+///         Ok(HttpResponse {
+///             status: StatusCode::OK,
+///             headers: HeaderMap::new(),
+///             body: br#"{"works": [], "total_count": 0, "next_page": null, "prev_page": null}"#.to_vec(),
+///         })
+///     }
+/// }
+///
+/// let client = Client::builder().transport(Recorded).build();
+/// assert_eq!(client.fetch(annis::works()).unwrap().total_count, 0);
+/// # }
+/// ```
+
+pub trait Transport: Debug + Send + Sync {
+    fn send(&self, request: HttpRequest) -> Result<HttpResponse, Error>;
+}
+
+/// What sends the requests of `nonblocking::Client`. Set with `nonblocking::ClientBuilder::transport()`.
+/// `reqwest::Client` is the default.
+
+#[cfg(feature = "async")]
+pub trait AsyncTransport: Debug + Send + Sync {
+    fn send(&self, request: HttpRequest) -> BoxFuture<'_, Result<HttpResponse, Error>>;
+}
+
+impl HttpResponse {
+    /// The body as UTF-8, replacing invalid sequences.
+    pub fn text(&self) -> String {
+        String::from_utf8_lossy(&self.body).into_owned()
+    }
+}
+
+impl From<HttpResponse> for http::Response<Vec<u8>> {
+    fn from(response: HttpResponse) -> Self {
+        let mut http = http::Response::new(response.body);
+        *http.status_mut() = response.status;
+        *http.headers_mut() = response.headers;
+        http
+    }
+}
+
+#[cfg(feature = "blocking")]
+impl Transport for reqwest::blocking::Client {
+    fn send(&self, request: HttpRequest) -> Result<HttpResponse, Error> {
+        let mut builder = self
+            .request(request.method, request.url)
+            .headers(request.headers);
+        if let Some(body) = request.body {
+            builder = builder.body(body);
+        }
+        let response = builder.send()?;
+        let status = response.status();
+        let headers = response.headers().clone();
+        Ok(HttpResponse {
+            status,
+            headers,
+            body: response.bytes()?.to_vec(),
+        })
+    }
+}
+
+#[cfg(feature = "async")]
+impl AsyncTransport for reqwest::Client {
+    fn send(&self, request: HttpRequest) -> BoxFuture<'_, Result<HttpResponse, Error>> {
+        let mut builder = self
+            .request(request.method, request.url)
+            .headers(request.headers);
+        if let Some(body) = request.body {
+            builder = builder.body(body);
+        }
+        Box::pin(async move {
+            let response = builder.send().await?;
+            let status = response.status();
+            let headers = response.headers().clone();
+            Ok(HttpResponse {
+                status,
+                headers,
+                body: response.bytes().await?.to_vec(),
+            })
+        })
+    }
+}
@@ -1,10 +1,266 @@
+#![cfg(any(feature = "blocking", feature = "async"))]
+
 extern crate annis;
-use annis::OAuth;
+mod common;
+
+#[cfg(feature = "blocking")]
+use annis::models::Revoked;
+#[cfg(feature = "blocking")]
+use annis::Client;
+use annis::{ErrorKind, Scope};
+#[cfg(feature = "blocking")]
+use annis::{OAuth, Scopes};
+use common::{MockServer, Response};
+#[cfg(feature = "blocking")]
 use std::env;
 
+const TOKEN: &str = r#"{
+    "access_token": "issued_token",
+    "token_type": "bearer",
+    "scope": "read write",
+    "created_at": 1460000000
+}"#;
+
+const INVALID_GRANT: &str = r#"{
+    "error": "invalid_grant",
+    "error_description": "The provided authorization grant is invalid."
+}"#;
+
+#[cfg(feature = "blocking")]
 #[test]
 fn authorize() {
     OAuth::client_id(env::var("annict_client_id").unwrap())
         .authorize_url()
         .build();
 }
+
+#[cfg(feature = "blocking")]
+#[test]
+fn access_token() {
+    let server = MockServer::start(|_| Response::json(200, TOKEN));
+    let token = OAuth::client_id("client_id")
+        .base_url(server.url())
+        .access_token()
+        .client_secret("client_secret_key")
+        .code("certification code")
+        .build()
+        .unwrap();
+    assert_eq!(token.access_token, "issued_token");
+    assert_eq!(token.token_type, "bearer");
+    assert_eq!(token.scope, Scope::Read | Scope::Write);
+    assert!(token.has_scope(Scope::Write));
+    assert_eq!(token.created_at, 1460000000);
+
+    let request = &server.requests()[0];
+    assert_eq!(request.path, "/oauth/token");
+    assert_eq!(request.query, None);
+    assert!(request.body.contains("grant_type=authorization_code"));
+    assert!(request.body.contains("code=certification+code"));
+}
+
+#[cfg(feature = "blocking")]
+#[test]
+fn invalid_grant() {
+    let server = MockServer::start(|_| Response::json(401, INVALID_GRANT));
+    let err = OAuth::client_id("client_id")
+        .base_url(server.url())
+        .access_token()
+        .code("expired code")
+        .build()
+        .unwrap_err();
+    match err.kind() {
+        ErrorKind::OAuth(e) => {
+            assert_eq!(e.error, "invalid_grant");
+            assert!(e.error_description.is_some());
+        }
+        kind => panic!("unexpected {:?}", kind),
+    }
+}
+
+#[cfg(feature = "blocking")]
+#[test]
+fn unreachable() {
+    let err = OAuth::client_id("client_id")
+        .base_url("http://127.0.0.1:1")
+        .access_token()
+        .build()
+        .unwrap_err();
+    assert!(matches!(err.kind(), ErrorKind::Network));
+}
+
+#[cfg(feature = "async")]
+#[tokio::test]
+async fn nonblocking_access_token() {
+    let server = MockServer::start(|_| Response::json(200, TOKEN));
+    let token = annis::nonblocking::OAuth::client_id("client_id")
+        .base_url(server.url())
+        .access_token()
+        .code("certification code")
+        .build_async()
+        .await
+        .unwrap();
+    assert_eq!(token.access_token, "issued_token");
+
+    let server = MockServer::start(|_| Response::json(400, INVALID_GRANT));
+    let err = annis::nonblocking::OAuth::client_id("client_id")
+        .base_url(server.url())
+        .access_token()
+        .build_async()
+        .await
+        .unwrap_err();
+    assert!(matches!(err.kind(), ErrorKind::OAuth(_)));
+}
+
+const TOKEN_INFO: &str = r#"{
+    "resource_owner_id": 2,
+    "scopes": ["read", "write"],
+    "expires_in_seconds": null,
+    "application": { "uid": "client_id" },
+    "created_at": 1460000000
+}"#;
+
+#[cfg(feature = "blocking")]
+#[test]
+fn info() {
+    let server = MockServer::start(|_| Response::json(200, TOKEN_INFO));
+    let client = Client::builder().token("token").base_url(server.url()).build();
+    let info = client.fetch(OAuth::info()).unwrap();
+    assert_eq!(info.resource_owner_id, 2);
+    assert_eq!(info.scope, vec![Scope::Read, Scope::Write]);
+    assert!(info.has_scope(Scope::Write));
+    assert_eq!(info.scopes(), Scope::Read | Scope::Write);
+    assert_eq!(info.expires_in_seconds, None);
+    assert_eq!(info.application.uid, "client_id");
+    assert_eq!(server.requests()[0].path, "/oauth/token/info");
+}
+
+#[cfg(feature = "blocking")]
+#[test]
+fn revoke() {
+    let server = MockServer::start(|_| Response::json(200, "{}"));
+    let client = Client::builder().token("token").base_url(server.url()).build();
+    assert_eq!(client.fetch(OAuth::revoke("old_token")).unwrap(), Revoked);
+
+    let request = &server.requests()[0];
+    assert_eq!(request.method, "POST");
+    assert_eq!(request.path, "/oauth/revoke");
+    assert_eq!(request.form_param("token").as_deref(), Some("old_token"));
+}
+
+#[cfg(feature = "async")]
+#[tokio::test]
+async fn nonblocking_info_and_revoke() {
+    let server = MockServer::start(|req| match req.path.as_str() {
+        "/oauth/token/info" => Response::json(200, TOKEN_INFO),
+        _ => Response::empty(200),
+    });
+    let client = annis::nonblocking::Client::builder()
+        .token("token")
+        .base_url(server.url())
+        .build();
+    let info = client
+        .fetch(annis::nonblocking::OAuth::info())
+        .await
+        .unwrap();
+    assert!(info.scope.contains(&Scope::Write));
+    client
+        .fetch(annis::nonblocking::OAuth::revoke("token"))
+        .await
+        .unwrap();
+}
+
+#[cfg(feature = "blocking")]
+#[test]
+fn invalid_authorize_base_url() {
+    let auth = OAuth::client_id("client_id").authorize_base_url("not a url");
+    let err = auth.authorize_url().try_build().unwrap_err();
+    assert!(matches!(err.kind(), ErrorKind::InvalidParameter(_)));
+
+    let url = OAuth::client_id("client_id").authorize_url().try_build();
+    assert_eq!(
+        url.unwrap(),
+        OAuth::client_id("client_id").authorize_url().build()
+    );
+}
+
+#[cfg(feature = "blocking")]
+#[test]
+fn authorize_url_encoding() {
+    let url = OAuth::client_id("client id&")
+        .authorize_url()
+        .redirect_uri("https://example.com/callback?from=annict&x=1")
+        .scope("read+write")
+        .state("abc/123")
+        .build();
+    let url = reqwest::Url::parse(&url).unwrap();
+    assert_eq!(url.as_str().split('?').next(), Some("https://annict.com/oauth/authorize"));
+    let params: Vec<(String, String)> = url.query_pairs().into_owned().collect();
+    let param = |key: &str| {
+        params
+            .iter()
+            .find(|(k, _)| k == key)
+            .map(|(_, v)| v.as_str())
+    };
+    assert_eq!(param("client_id"), Some("client id&"));
+    assert_eq!(
+        param("redirect_uri"),
+        Some("https://example.com/callback?from=annict&x=1")
+    );
+    assert_eq!(param("response_type"), Some("code"));
+    assert_eq!(param("scope"), Some("read write"));
+    assert_eq!(param("state"), Some("abc/123"));
+}
+
+#[cfg(feature = "blocking")]
+#[test]
+fn verify_callback() {
+    let state = annis::random_state();
+    assert_ne!(state, annis::random_state());
+    assert_eq!(state.len(), 32);
+    assert!(state.chars().all(|c| c.is_ascii_hexdigit()));
+
+    let callback = format!("https://example.com/callback?code=issued_code&state={}", state);
+    assert_eq!(annis::verify_callback(&callback, &state).unwrap(), "issued_code");
+
+    let callback = format!("/callback?state={}&code=issued%20code", state);
+    assert_eq!(annis::verify_callback(&callback, &state).unwrap(), "issued code");
+
+    let err = annis::verify_callback("/callback?code=issued_code&state=forged", &state).unwrap_err();
+    assert!(matches!(err.kind(), ErrorKind::StateMismatch));
+
+    let forged = format!("/callback?code=issued_code&state={}0", &state[..31]);
+    let err = annis::verify_callback(&forged, &state).unwrap_err();
+    assert!(matches!(err.kind(), ErrorKind::StateMismatch));
+
+    let err = annis::verify_callback("/callback?code=issued_code", &state).unwrap_err();
+    assert!(matches!(err.kind(), ErrorKind::StateMismatch));
+
+    let callback = format!("/callback?error=access_denied&state={}", state);
+    let err = annis::verify_callback(&callback, &state).unwrap_err();
+    assert!(matches!(err.kind(), ErrorKind::OAuth(e) if e.error == "access_denied"));
+
+    let err = annis::verify_callback("/callback?error=access_denied", &state).unwrap_err();
+    assert!(matches!(err.kind(), ErrorKind::StateMismatch));
+}
+
+#[cfg(feature = "blocking")]
+#[test]
+fn scopes() {
+    let scopes = Scope::Read | Scope::Write;
+    assert_eq!(scopes.to_string(), "read write");
+    assert_eq!("read+write".parse::<Scopes>().unwrap(), scopes);
+    assert_eq!("write read".parse::<Scopes>().unwrap(), scopes);
+    assert_eq!("read".parse::<Scopes>().unwrap(), Scope::Read.into());
+    assert!("read admin".parse::<Scopes>().is_err());
+    assert!(!Scopes::from(Scope::Read).contains(Scope::Write));
+
+    let url = OAuth::client_id("client_id")
+        .authorize_url()
+        .scope(scopes)
+        .build();
+    let url = reqwest::Url::parse(&url).unwrap();
+    assert!(url.query_pairs().any(|(k, v)| k == "scope" && v == "read write"));
+
+    let read_only: Scopes = serde_json::from_str(r#""read""#).unwrap();
+    assert_eq!(serde_json::to_string(&(read_only | Scope::Write)).unwrap(), r#""read write""#);
+}
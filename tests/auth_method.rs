@@ -0,0 +1,102 @@
+#![cfg(any(feature = "blocking", feature = "async"))]
+
+extern crate annis;
+mod common;
+
+#[cfg(feature = "blocking")]
+use annis::models::TokenResponse;
+#[cfg(feature = "blocking")]
+use annis::Client;
+#[cfg(feature = "blocking")]
+use annis::{AuthMethod, MemoryTokenStore, Scopes, TokenStore};
+use common::{MockServer, Response};
+
+const WORKS: &str = r#"{"works": [], "total_count": 0, "next_page": null, "prev_page": null}"#;
+
+#[cfg(feature = "blocking")]
+#[test]
+fn bearer() {
+    let server = MockServer::start(|_| Response::json(200, WORKS));
+    let client = Client::builder()
+        .token("secret_token")
+        .base_url(server.url())
+        .build();
+    client.fetch(annis::works()).unwrap();
+
+    let request = &server.requests()[0];
+    assert_eq!(request.header("authorization"), Some("Bearer secret_token"));
+    assert_eq!(request.param("access_token"), None);
+}
+
+#[cfg(feature = "blocking")]
+#[test]
+fn query_parameter() {
+    let server = MockServer::start(|_| Response::json(200, WORKS));
+    let client = Client::builder()
+        .token("secret_token")
+        .base_url(server.url())
+        .auth_method(AuthMethod::QueryParameter)
+        .build();
+    client.fetch(annis::works()).unwrap();
+
+    let request = &server.requests()[0];
+    assert_eq!(request.header("authorization"), None);
+    assert_eq!(request.param("access_token").as_deref(), Some("secret_token"));
+}
+
+#[cfg(feature = "blocking")]
+#[test]
+fn debug_redacts_token() {
+    let builder = Client::builder().token("secret_token").clone();
+    let client = builder.build();
+    assert!(!format!("{:?}", builder).contains("secret_token"));
+    assert!(!format!("{:?}", client).contains("secret_token"));
+
+    #[cfg(feature = "async")]
+    {
+        let client = annis::nonblocking::Client::set_token("secret_token");
+        assert!(!format!("{:?}", client).contains("secret_token"));
+    }
+}
+
+#[cfg(feature = "blocking")]
+#[test]
+fn debug_redacts_stored_token() {
+    let token = TokenResponse {
+        access_token: "secret_token".to_string(),
+        token_type: "bearer".to_string(),
+        scope: Scopes::default(),
+        created_at: 0,
+    };
+    assert!(!format!("{:?}", token).contains("secret_token"));
+
+    let store = MemoryTokenStore::new();
+    store.save(&token).unwrap();
+    let client = Client::from_store(store).unwrap();
+    assert!(!format!("{:?}", client).contains("secret_token"));
+
+    #[cfg(feature = "async")]
+    {
+        let store = MemoryTokenStore::new();
+        store.save(&token).unwrap();
+        let client = annis::nonblocking::Client::builder()
+            .build_with_store(store)
+            .unwrap();
+        assert!(!format!("{:?}", client).contains("secret_token"));
+    }
+}
+
+#[cfg(feature = "async")]
+#[tokio::test]
+async fn nonblocking() {
+    let server = MockServer::start(|_| Response::json(200, WORKS));
+    let client = annis::nonblocking::Client::builder()
+        .token("secret_token")
+        .base_url(server.url())
+        .build();
+    client.fetch(annis::works()).await.unwrap();
+    assert_eq!(
+        server.requests()[0].header("authorization"),
+        Some("Bearer secret_token")
+    );
+}
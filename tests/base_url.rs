@@ -0,0 +1,128 @@
+#![cfg(any(feature = "blocking", feature = "async"))]
+
+extern crate annis;
+mod common;
+
+#[cfg(feature = "blocking")]
+use annis::Client;
+#[cfg(feature = "blocking")]
+use annis::{OAuth, Works};
+use common::{MockServer, Response};
+
+const WORKS: &str = r#"{
+    "works": [{ "id": 860, "title": "serial experiments lain" }],
+    "total_count": 1,
+    "next_page": null,
+    "prev_page": null
+}"#;
+
+#[cfg(feature = "blocking")]
+#[test]
+fn call() {
+    let server = MockServer::start(|_| Response::json(200, WORKS));
+    let client = Client::builder()
+        .token("access_token")
+        .base_url(server.url())
+        .build();
+
+    let res = client
+        .fetch(annis::works().params(vec![(Works::filter_title, "lain")]))
+        .unwrap();
+    assert_eq!(res.works[0].title, "serial experiments lain");
+
+    let requests = server.requests();
+    assert_eq!(requests[0].method, "GET");
+    assert_eq!(requests[0].path, "/v1/works");
+    assert_eq!(requests[0].param("filter_title").as_deref(), Some("lain"));
+}
+
+#[cfg(feature = "blocking")]
+#[test]
+fn trailing_slash() {
+    let server = MockServer::start(|_| Response::json(200, WORKS));
+    let client = Client::builder()
+        .base_url(format!("{}/", server.url()))
+        .build();
+    client.fetch(annis::works()).unwrap();
+    assert_eq!(server.requests()[0].path, "/v1/works");
+}
+
+#[cfg(feature = "blocking")]
+#[test]
+fn paginate() {
+    let server = MockServer::start(|req| {
+        let page: u64 = req.param("page").unwrap().parse().unwrap();
+        let next_page = if page < 3 {
+            (page + 1).to_string()
+        } else {
+            "null".to_string()
+        };
+        Response::json(
+            200,
+            &format!(
+                r#"{{"works": [{{"id": {0}1, "title": "a"}}, {{"id": {0}2, "title": "b"}}],
+                    "total_count": 6, "next_page": {1}, "prev_page": null}}"#,
+                page, next_page
+            ),
+        )
+    });
+    let client = Client::builder().base_url(server.url()).build();
+
+    let ids: Vec<u64> = client
+        .paginate(annis::works())
+        .map(|work| work.unwrap().id)
+        .collect();
+    assert_eq!(ids, vec![11, 12, 21, 22, 31, 32]);
+
+    let ids: Vec<u64> = client
+        .paginate(annis::works().params(vec![(Works::page, "2")]))
+        .max_items(3)
+        .map(|work| work.unwrap().id)
+        .collect();
+    assert_eq!(ids, vec![21, 22, 31]);
+}
+
+#[cfg(feature = "async")]
+#[tokio::test]
+async fn nonblocking() {
+    use futures::TryStreamExt;
+
+    let server = MockServer::start(|_| Response::json(200, WORKS));
+    let client = annis::nonblocking::Client::builder()
+        .base_url(server.url())
+        .build();
+
+    let res = client.fetch(annis::works()).await.unwrap();
+    assert_eq!(res.total_count, 1);
+
+    let works: Vec<_> = client.paginate(annis::works()).try_collect().await.unwrap();
+    assert_eq!(works.len(), 1);
+    assert_eq!(server.requests()[1].path, "/v1/works");
+}
+
+#[cfg(feature = "blocking")]
+#[test]
+fn oauth() {
+    let server = MockServer::start(|req| match req.path.as_str() {
+        "/oauth/token" => Response::json(
+            200,
+            r#"{"access_token": "issued_token", "token_type": "bearer", "scope": "read", "created_at": 1460000000}"#,
+        ),
+        _ => Response::empty(404),
+    });
+    let auth = OAuth::client_id("client_id")
+        .base_url(server.url())
+        .authorize_base_url("http://127.0.0.1:8080");
+
+    let url = auth.authorize_url().build();
+    assert!(url.starts_with("http://127.0.0.1:8080/oauth/authorize?client_id=client_id"));
+
+    let token = auth
+        .access_token()
+        .client_secret("client_secret_key")
+        .code("certification code")
+        .build()
+        .unwrap();
+    assert_eq!(token.access_token, "issued_token");
+    assert_eq!(server.requests()[0].method, "POST");
+}
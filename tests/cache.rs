@@ -0,0 +1,224 @@
+#![cfg(any(feature = "blocking", feature = "async"))]
+
+extern crate annis;
+mod common;
+
+#[cfg(feature = "blocking")]
+use annis::Client;
+use annis::ResponseCache;
+#[cfg(feature = "blocking")]
+use annis::{CacheStore, CachedResponse, DiskCache, MemoryCache, Works};
+use common::{MockServer, Response};
+#[cfg(feature = "blocking")]
+use std::env;
+#[cfg(feature = "blocking")]
+use std::fs;
+use std::time::Duration;
+
+const WORKS: &str = r#"{
+    "works": [{ "id": 860, "title": "serial experiments lain" }],
+    "total_count": 1,
+    "next_page": null,
+    "prev_page": null
+}"#;
+
+/// A server which answers `304 Not Modified` when the ETag matches.
+fn etag_server() -> MockServer {
+    MockServer::start(|req| {
+        if req.header("if-none-match") == Some("\"v1\"") {
+            Response::empty(304).header("ETag", "\"v1\"")
+        } else {
+            Response::json(200, WORKS)
+                .header("ETag", "\"v1\"")
+                .header("Last-Modified", "Wed, 21 Oct 2015 07:28:00 GMT")
+        }
+    })
+}
+
+#[cfg(feature = "blocking")]
+#[test]
+fn fresh() {
+    let server = etag_server();
+    let client = Client::builder()
+        .base_url(server.url())
+        .token("access_token")
+        .cache(ResponseCache::memory(10))
+        .build();
+
+    let works = annis::works().params(vec![(Works::filter_title, "lain")]);
+    let first = client.fetch(works.clone()).unwrap();
+    let second = client.fetch(works).unwrap();
+    assert_eq!(first, second);
+    assert_eq!(server.requests().len(), 1);
+
+    // -> Other parameters are another entry.
+    client
+        .fetch(annis::works().params(vec![(Works::filter_title, "haruhi")]))
+        .unwrap();
+    assert_eq!(server.requests().len(), 2);
+}
+
+#[cfg(feature = "blocking")]
+#[test]
+fn revalidate() {
+    let server = etag_server();
+    let client = Client::builder()
+        .base_url(server.url())
+        .cache(ResponseCache::memory(10).ttl(Duration::ZERO))
+        .build();
+
+    client.fetch(annis::works()).unwrap();
+    let res = client.call(annis::works()).unwrap();
+    assert_eq!(res.status(), 200);
+    assert_eq!(res.headers()["etag"], "\"v1\"");
+    let res: annis::models::WorksResponse = serde_json::from_str(&res.text().unwrap()).unwrap();
+    assert_eq!(res.works[0].title, "serial experiments lain");
+
+    let requests = server.requests();
+    assert_eq!(requests.len(), 2);
+    assert_eq!(requests[0].header("if-none-match"), None);
+    assert_eq!(requests[1].header("if-none-match"), Some("\"v1\""));
+    assert_eq!(
+        requests[1].header("if-modified-since"),
+        Some("Wed, 21 Oct 2015 07:28:00 GMT")
+    );
+}
+
+#[cfg(feature = "blocking")]
+#[test]
+fn not_cached() {
+    let server = MockServer::start(|req| match req.path.as_str() {
+        "/v1/me/statuses" => Response::empty(204),
+        _ => Response::json(200, WORKS).header("Cache-Control", "no-store"),
+    });
+    let client = Client::builder()
+        .base_url(server.url())
+        .cache(ResponseCache::memory(10))
+        .build();
+
+    client.fetch(annis::works()).unwrap();
+    client.fetch(annis::works()).unwrap();
+    client.call(annis::me_statuses()).unwrap();
+    client.call(annis::me_statuses()).unwrap();
+    assert_eq!(server.requests().len(), 4);
+}
+
+#[cfg(feature = "blocking")]
+#[test]
+fn tokens_are_separate() {
+    let server = etag_server();
+    let cache = ResponseCache::memory(10);
+    for token in &["alice", "bob", "alice"] {
+        Client::builder()
+            .base_url(server.url())
+            .token(*token)
+            .cache(cache.clone())
+            .build()
+            .fetch(annis::works())
+            .unwrap();
+    }
+    assert_eq!(server.requests().len(), 2);
+}
+
+#[cfg(feature = "blocking")]
+#[test]
+fn memory_lru() {
+    let store = MemoryCache::new(2);
+    let response = CachedResponse {
+        status: 200,
+        headers: vec![],
+        body: b"{}".to_vec(),
+        stored_at: 0,
+    };
+    store.put("a", &response).unwrap();
+    store.put("b", &response).unwrap();
+    store.get("a").unwrap();
+    store.put("c", &response).unwrap();
+
+    assert_eq!(store.len(), 2);
+    assert!(store.get("a").unwrap().is_some());
+    assert!(store.get("b").unwrap().is_none());
+    assert!(store.get("c").unwrap().is_some());
+}
+
+#[cfg(feature = "blocking")]
+#[test]
+fn disk() {
+    let dir = env::temp_dir().join(format!("annis-test-{}-cache", std::process::id()));
+    let _ = fs::remove_dir_all(&dir);
+    let server = etag_server();
+
+    for _ in 0..2 {
+        // -> A new client on each run, sharing only the directory.
+        let client = Client::builder()
+            .base_url(server.url())
+            .cache(ResponseCache::disk(&dir))
+            .build();
+        let res = client.fetch(annis::works()).unwrap();
+        assert_eq!(res.total_count, 1);
+    }
+    assert_eq!(server.requests().len(), 1);
+
+    let store = DiskCache::new(&dir);
+    assert_eq!(fs::read_dir(store.dir()).unwrap().count(), 1);
+
+    #[cfg(unix)]
+    {
+        use std::os::unix::fs::PermissionsExt;
+        let mode =
+            |path: &std::path::Path| fs::metadata(path).unwrap().permissions().mode() & 0o777;
+        assert_eq!(mode(&dir), 0o700);
+        for entry in fs::read_dir(&dir).unwrap() {
+            assert_eq!(mode(&entry.unwrap().path()), 0o600);
+        }
+    }
+    let _ = fs::remove_dir_all(&dir);
+}
+
+#[cfg(feature = "blocking")]
+#[test]
+fn disk_concurrent_puts() {
+    let dir = env::temp_dir().join(format!("annis-test-{}-cache-race", std::process::id()));
+    let _ = fs::remove_dir_all(&dir);
+    let response = CachedResponse {
+        status: 200,
+        headers: vec![],
+        body: b"{}".to_vec(),
+        stored_at: 0,
+    };
+    DiskCache::new(&dir).put("a", &response).unwrap();
+    let entry = fs::read_dir(&dir).unwrap().next().unwrap().unwrap().path();
+    let mut planted = entry.into_os_string();
+    planted.push(".tmp");
+    fs::write(&planted, "planted").unwrap();
+
+    let handles: Vec<_> = (0..8)
+        .map(|_| {
+            let store = DiskCache::new(&dir);
+            let response = response.clone();
+            std::thread::spawn(move || store.put("a", &response))
+        })
+        .collect();
+    for handle in handles {
+        handle.join().unwrap().unwrap();
+    }
+    assert_eq!(DiskCache::new(&dir).get("a").unwrap(), Some(response));
+    assert_eq!(fs::read_to_string(&planted).unwrap(), "planted");
+    assert_eq!(fs::read_dir(&dir).unwrap().count(), 2);
+    let _ = fs::remove_dir_all(&dir);
+}
+
+#[cfg(feature = "async")]
+#[tokio::test]
+async fn nonblocking() {
+    let server = etag_server();
+    let client = annis::nonblocking::Client::builder()
+        .base_url(server.url())
+        .cache(ResponseCache::memory(10).ttl(Duration::ZERO))
+        .build();
+
+    client.fetch(annis::works()).await.unwrap();
+    let res = client.fetch(annis::works()).await.unwrap();
+    assert_eq!(res.works[0].id, 860);
+    assert_eq!(server.requests()[1].header("if-none-match"), Some("\"v1\""));
+}
@@ -1,3 +1,5 @@
+#![cfg(feature = "blocking")]
+
 extern crate annis;
 use annis::Client;
 use std::env;
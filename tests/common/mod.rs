@@ -0,0 +1,237 @@
+// A stand-in for the Annict API which runs on a local port.
+
+#![allow(dead_code)]
+
+use std::io::{BufRead, BufReader, Read, Write};
+use std::net::{TcpListener, TcpStream};
+use std::sync::atomic::{AtomicUsize, Ordering};
+use std::sync::{Arc, Mutex};
+use std::thread;
+
+#[derive(Debug, Clone)]
+pub struct Request {
+    pub method: String,
+    pub path: String,
+    pub query: Option<String>,
+    pub headers: Vec<(String, String)>,
+    pub body: String,
+}
+
+impl Request {
+    pub fn header(&self, name: &str) -> Option<&str> {
+        self.headers
+            .iter()
+            .find(|(k, _)| k.eq_ignore_ascii_case(name))
+            .map(|(_, v)| v.as_str())
+    }
+
+    /// Decoded query parameters.
+    pub fn params(&self) -> Vec<(String, String)> {
+        let url = format!("http://127.0.0.1/?{}", self.query.as_deref().unwrap_or(""));
+        reqwest::Url::parse(&url)
+            .unwrap()
+            .query_pairs()
+            .into_owned()
+            .collect()
+    }
+
+    pub fn param(&self, key: &str) -> Option<String> {
+        self.params()
+            .into_iter()
+            .find(|(k, _)| k == key)
+            .map(|(_, v)| v)
+    }
+
+    /// Decoded parameters of a form body.
+    pub fn form(&self) -> Vec<(String, String)> {
+        let url = format!("http://127.0.0.1/?{}", self.body);
+        reqwest::Url::parse(&url)
+            .unwrap()
+            .query_pairs()
+            .into_owned()
+            .collect()
+    }
+
+    pub fn form_param(&self, key: &str) -> Option<String> {
+        self.form()
+            .into_iter()
+            .find(|(k, _)| k == key)
+            .map(|(_, v)| v)
+    }
+}
+
+#[derive(Debug, Clone)]
+pub struct Response {
+    pub status: u16,
+    pub headers: Vec<(String, String)>,
+    pub body: String,
+}
+
+impl Response {
+    pub fn json(status: u16, body: &str) -> Response {
+        Response {
+            status,
+            headers: vec![("Content-Type".into(), "application/json".into())],
+            body: body.to_string(),
+        }
+    }
+
+    pub fn empty(status: u16) -> Response {
+        Response {
+            status,
+            headers: vec![],
+            body: String::new(),
+        }
+    }
+
+    pub fn header(mut self, name: &str, value: &str) -> Response {
+        self.headers.push((name.to_string(), value.to_string()));
+        self
+    }
+}
+
+pub struct MockServer {
+    port: u16,
+    requests: Arc<Mutex<Vec<Request>>>,
+    connections: Arc<AtomicUsize>,
+}
+
+impl MockServer {
+    /// Serve every request with `handler` on a background thread.
+    pub fn start<F>(handler: F) -> MockServer
+    where
+        F: Fn(&Request) -> Response + Send + 'static,
+    {
+        let listener = TcpListener::bind("127.0.0.1:0").unwrap();
+        let port = listener.local_addr().unwrap().port();
+        let requests = Arc::new(Mutex::new(Vec::new()));
+        let connections = Arc::new(AtomicUsize::new(0));
+        let log = requests.clone();
+        let accepted = connections.clone();
+        thread::spawn(move || {
+            for stream in listener.incoming() {
+                let mut stream = match stream {
+                    Ok(stream) => stream,
+                    Err(_) => continue,
+                };
+                accepted.fetch_add(1, Ordering::SeqCst);
+                if let Some(request) = read_request(&mut stream) {
+                    log.lock().unwrap().push(request.clone());
+                    write_response(&mut stream, &handler(&request), false);
+                }
+            }
+        });
+        MockServer {
+            port,
+            requests,
+            connections,
+        }
+    }
+
+    /// Like `start`, but keeps each connection open for further requests.
+    pub fn keep_alive<F>(handler: F) -> MockServer
+    where
+        F: Fn(&Request) -> Response + Send + Sync + 'static,
+    {
+        let listener = TcpListener::bind("127.0.0.1:0").unwrap();
+        let port = listener.local_addr().unwrap().port();
+        let requests = Arc::new(Mutex::new(Vec::new()));
+        let connections = Arc::new(AtomicUsize::new(0));
+        let log = requests.clone();
+        let accepted = connections.clone();
+        let handler = Arc::new(handler);
+        thread::spawn(move || {
+            for stream in listener.incoming() {
+                let mut stream = match stream {
+                    Ok(stream) => stream,
+                    Err(_) => continue,
+                };
+                accepted.fetch_add(1, Ordering::SeqCst);
+                let log = log.clone();
+                let handler = handler.clone();
+                thread::spawn(move || {
+                    while let Some(request) = read_request(&mut stream) {
+                        log.lock().unwrap().push(request.clone());
+                        write_response(&mut stream, &handler(&request), true);
+                    }
+                });
+            }
+        });
+        MockServer {
+            port,
+            requests,
+            connections,
+        }
+    }
+
+    pub fn url(&self) -> String {
+        format!("http://127.0.0.1:{}", self.port)
+    }
+
+    pub fn requests(&self) -> Vec<Request> {
+        self.requests.lock().unwrap().clone()
+    }
+
+    /// The number of accepted TCP connections.
+    pub fn connections(&self) -> usize {
+        self.connections.load(Ordering::SeqCst)
+    }
+}
+
+fn read_request(stream: &mut TcpStream) -> Option<Request> {
+    let mut reader = BufReader::new(stream);
+    let mut line = String::new();
+    reader.read_line(&mut line).ok()?;
+    let mut parts = line.split_whitespace();
+    let method = parts.next()?.to_string();
+    let target = parts.next()?.to_string();
+    let (path, query) = match target.find('?') {
+        Some(i) => (target[..i].to_string(), Some(target[i + 1..].to_string())),
+        None => (target, None),
+    };
+
+    let mut headers = Vec::new();
+    loop {
+        let mut line = String::new();
+        reader.read_line(&mut line).ok()?;
+        let line = line.trim_end();
+        if line.is_empty() {
+            break;
+        }
+        if let Some(i) = line.find(':') {
+            headers.push((line[..i].to_string(), line[i + 1..].trim().to_string()));
+        }
+    }
+
+    let length = headers
+        .iter()
+        .find(|(k, _)| k.eq_ignore_ascii_case("content-length"))
+        .and_then(|(_, v)| v.parse().ok())
+        .unwrap_or(0);
+    let mut body = vec![0; length];
+    reader.read_exact(&mut body).ok()?;
+
+    Some(Request {
+        method,
+        path,
+        query,
+        headers,
+        body: String::from_utf8_lossy(&body).into_owned(),
+    })
+}
+
+fn write_response(stream: &mut TcpStream, response: &Response, keep_alive: bool) {
+    let mut head = format!(
+        "HTTP/1.1 {} Mock\r\nContent-Length: {}\r\nConnection: {}\r\n",
+        response.status,
+        response.body.len(),
+        if keep_alive { "keep-alive" } else { "close" }
+    );
+    for (name, value) in &response.headers {
+        head.push_str(&format!("{}: {}\r\n", name, value));
+    }
+    head.push_str("\r\n");
+    let _ = stream.write_all(head.as_bytes());
+    let _ = stream.write_all(response.body.as_bytes());
+    let _ = stream.flush();
+}
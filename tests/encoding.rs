@@ -0,0 +1,105 @@
+#![cfg(any(feature = "blocking", feature = "async"))]
+
+extern crate annis;
+mod common;
+
+#[cfg(feature = "blocking")]
+use annis::Client;
+#[cfg(feature = "blocking")]
+use annis::{Encoding, MeReviews, Works};
+use annis::{MeRecords, Method};
+use common::{MockServer, Response};
+
+#[cfg(feature = "blocking")]
+#[test]
+fn get_uses_query() {
+    let server = MockServer::start(|_| {
+        Response::json(
+            200,
+            r#"{"works": [], "total_count": 0, "next_page": null, "prev_page": null}"#,
+        )
+    });
+    let client = Client::builder().base_url(server.url()).build();
+    client
+        .fetch(annis::works().params(vec![(Works::filter_title, "lain")]))
+        .unwrap();
+
+    let request = &server.requests()[0];
+    assert_eq!(request.param("filter_title").as_deref(), Some("lain"));
+    assert!(request.body.is_empty());
+}
+
+#[cfg(feature = "blocking")]
+#[test]
+fn post_uses_form() {
+    let server = MockServer::start(|_| Response::json(200, "{}"));
+    let client = Client::builder().base_url(server.url()).build();
+    let comment = "a".repeat(10_000);
+    client
+        .call(annis::me_records(Method::POST, 5013).params(vec![
+            (MeRecords::comment, comment.as_str()),
+            (MeRecords::rating, "5"),
+        ]))
+        .unwrap();
+    client
+        .call(annis::me_reviews(Method::PATCH, 10).params(vec![(MeReviews::body, "so good")]))
+        .unwrap();
+
+    let requests = server.requests();
+    assert_eq!(requests[0].method, "POST");
+    assert_eq!(requests[0].query, None);
+    assert_eq!(
+        requests[0].header("content-type"),
+        Some("application/x-www-form-urlencoded")
+    );
+    assert_eq!(requests[0].form_param("episode_id").as_deref(), Some("5013"));
+    assert_eq!(requests[0].form_param("comment"), Some(comment));
+    assert_eq!(requests[1].method, "PATCH");
+    assert_eq!(requests[1].path, "/v1/me/reviews/10");
+    assert_eq!(requests[1].form_param("body").as_deref(), Some("so good"));
+}
+
+#[cfg(feature = "blocking")]
+#[test]
+fn json() {
+    let server = MockServer::start(|_| Response::json(200, "{}"));
+    let client = Client::builder().base_url(server.url()).build();
+    client
+        .call(
+            annis::me_reviews(Method::POST, 3994)
+                .params(vec![(MeReviews::body, "so good")])
+                .encoding(Encoding::Json),
+        )
+        .unwrap();
+
+    let request = &server.requests()[0];
+    assert_eq!(request.header("content-type"), Some("application/json"));
+    let body: annis::Value = serde_json::from_str(&request.body).unwrap();
+    assert_eq!(body["work_id"], "3994");
+    assert_eq!(body["body"], "so good");
+}
+
+#[cfg(feature = "blocking")]
+#[test]
+fn delete_uses_query() {
+    let service = annis::me_records(Method::DELETE, 1838569);
+    assert_eq!(service.encoding, Encoding::Query);
+    assert_eq!(annis::me_statuses().encoding, Encoding::Form);
+}
+
+#[cfg(feature = "async")]
+#[tokio::test]
+async fn nonblocking() {
+    let server = MockServer::start(|_| Response::json(200, "{}"));
+    let client = annis::nonblocking::Client::builder()
+        .base_url(server.url())
+        .build();
+    client
+        .call(annis::me_records(Method::PATCH, 1838569).params(vec![(MeRecords::rating, "5")]))
+        .await
+        .unwrap();
+
+    let request = &server.requests()[0];
+    assert_eq!(request.query, None);
+    assert_eq!(request.form_param("rating").as_deref(), Some("5"));
+}
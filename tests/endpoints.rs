@@ -0,0 +1,106 @@
+#![cfg(feature = "blocking")]
+
+extern crate annis;
+mod common;
+
+use annis::{Client, Method, OAuth, Reviews, Service};
+use common::{MockServer, Response};
+use serde::Serialize;
+
+const REVIEWS: &str = r#"{
+    "reviews": [{
+        "id": 1,
+        "title": "Lain",
+        "body": "Present day, present time.",
+        "rating_animation_state": "great",
+        "rating_music_state": "good",
+        "rating_story_state": "great",
+        "rating_character_state": "average",
+        "rating_overall_state": "great",
+        "likes_count": 12,
+        "impressions_count": 345,
+        "work": { "id": 860, "title": "serial experiments lain" },
+        "user": { "id": 2, "username": "lain" }
+    }],
+    "total_count": 1,
+    "next_page": null,
+    "prev_page": null
+}"#;
+
+/// Sends `service` and returns the method and the path which the server received.
+fn sent<K>(service: Service<K>) -> (String, String)
+where
+    K: Serialize + Into<String> + PartialEq,
+{
+    let server = MockServer::start(|_| Response::json(200, "{}"));
+    let client = Client::builder().base_url(server.url()).build();
+    client.call(service).unwrap();
+    let request = server.requests().remove(0);
+    (request.method, request.path)
+}
+
+macro_rules! assert_sent {
+    ($service:expr, $method:expr, $path:expr) => {
+        assert_eq!(
+            sent($service),
+            ($method.to_string(), $path.to_string()),
+            "{}",
+            stringify!($service)
+        );
+    };
+}
+
+#[test]
+fn paths() {
+    assert_sent!(annis::works(), "GET", "/v1/works");
+    assert_sent!(annis::episodes(), "GET", "/v1/episodes");
+    assert_sent!(annis::records(), "GET", "/v1/records");
+    assert_sent!(annis::reviews(), "GET", "/v1/reviews");
+    assert_sent!(annis::users(), "GET", "/v1/users");
+    assert_sent!(annis::following(), "GET", "/v1/following");
+    assert_sent!(annis::followers(), "GET", "/v1/followers");
+    assert_sent!(annis::activities(), "GET", "/v1/activities");
+    assert_sent!(annis::people(), "GET", "/v1/people");
+    assert_sent!(annis::organizations(), "GET", "/v1/organizations");
+    assert_sent!(annis::series(), "GET", "/v1/series");
+    assert_sent!(annis::characters(), "GET", "/v1/characters");
+    assert_sent!(annis::casts(), "GET", "/v1/casts");
+    assert_sent!(annis::staffs(), "GET", "/v1/staffs");
+    assert_sent!(annis::me(), "GET", "/v1/me");
+    assert_sent!(annis::me_works(), "GET", "/v1/me/works");
+    assert_sent!(annis::me_programs(), "GET", "/v1/me/programs");
+    assert_sent!(annis::me_statuses(), "POST", "/v1/me/statuses");
+    assert_sent!(
+        annis::me_following_activities(),
+        "GET",
+        "/v1/me/following_activities"
+    );
+    assert_sent!(annis::me_records(Method::POST, 5013), "POST", "/v1/me/records");
+    assert_sent!(annis::me_records(Method::PATCH, 10), "PATCH", "/v1/me/records/10");
+    assert_sent!(annis::me_records(Method::DELETE, 10), "DELETE", "/v1/me/records/10");
+    assert_sent!(annis::me_reviews(Method::POST, 860), "POST", "/v1/me/reviews");
+    assert_sent!(annis::me_reviews(Method::PATCH, 10), "PATCH", "/v1/me/reviews/10");
+    assert_sent!(annis::me_reviews(Method::DELETE, 10), "DELETE", "/v1/me/reviews/10");
+    assert_sent!(OAuth::info(), "GET", "/oauth/token/info");
+    assert_sent!(OAuth::revoke("token"), "POST", "/oauth/revoke");
+}
+
+#[test]
+fn reviews() {
+    let server = MockServer::start(|_| Response::json(200, REVIEWS));
+    let client = Client::builder().base_url(server.url()).build();
+    let res = client
+        .fetch(annis::reviews().params(vec![(Reviews::filter_work_id, "860")]))
+        .unwrap();
+
+    let review = &res.reviews[0];
+    assert_eq!(review.title.as_deref(), Some("Lain"));
+    assert_eq!(review.likes_count, Some(12));
+    assert_eq!(review.impressions_count, Some(345));
+    assert_eq!(review.work.as_ref().unwrap().title, "serial experiments lain");
+    assert_eq!(review.user.as_ref().unwrap().username, "lain");
+
+    let request = &server.requests()[0];
+    assert_eq!(request.path, "/v1/reviews");
+    assert_eq!(request.param("filter_work_id").as_deref(), Some("860"));
+}
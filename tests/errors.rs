@@ -0,0 +1,108 @@
+#![cfg(any(feature = "blocking", feature = "async"))]
+
+extern crate annis;
+mod common;
+
+#[cfg(feature = "blocking")]
+use annis::Client;
+#[cfg(feature = "blocking")]
+use annis::{Error, ErrorKind};
+use common::{MockServer, Response};
+#[cfg(feature = "blocking")]
+use std::time::Duration;
+
+const UNAUTHORIZED: &str = r#"{
+    "errors": [{
+        "type": "invalid_token",
+        "short_message": "Invalid token",
+        "message": "The access token is invalid"
+    }]
+}"#;
+
+#[cfg(feature = "blocking")]
+fn client(server: &MockServer) -> Client {
+    Client::builder().token("token").base_url(server.url()).build()
+}
+
+#[cfg(feature = "blocking")]
+#[test]
+fn unauthorized() {
+    let server = MockServer::start(|_| Response::json(401, UNAUTHORIZED));
+    let err = client(&server).call(annis::works()).unwrap_err();
+    match err.kind() {
+        ErrorKind::Unauthorized(errors) => assert_eq!(errors[0].kind, "invalid_token"),
+        kind => panic!("unexpected {:?}", kind),
+    }
+    assert_eq!(err.status().map(|s| s.as_u16()), Some(401));
+    assert_eq!(err.to_string(), "Unauthorized access token: The access token is invalid");
+}
+
+#[cfg(feature = "blocking")]
+#[test]
+fn not_found() {
+    let server = MockServer::start(|_| Response::empty(404));
+    let err = client(&server).fetch(annis::works()).unwrap_err();
+    assert!(matches!(err.kind(), ErrorKind::NotFound(errors) if errors.is_empty()));
+}
+
+#[cfg(feature = "blocking")]
+#[test]
+fn rate_limited() {
+    let server = MockServer::start(|_| Response::empty(429).header("Retry-After", "30"));
+    let err = client(&server).fetch(annis::works()).unwrap_err();
+    match err.kind() {
+        ErrorKind::RateLimited { retry_after, .. } => {
+            assert_eq!(*retry_after, Some(Duration::from_secs(30)))
+        }
+        kind => panic!("unexpected {:?}", kind),
+    }
+}
+
+#[cfg(feature = "blocking")]
+#[test]
+fn server_error() {
+    let server = MockServer::start(|_| Response::empty(503));
+    let err = client(&server).fetch(annis::works()).unwrap_err();
+    assert_eq!(err.status().map(|s| s.as_u16()), Some(503));
+    assert!(matches!(err.kind(), ErrorKind::Status { .. }));
+}
+
+#[cfg(feature = "blocking")]
+#[test]
+fn decode() {
+    let server = MockServer::start(|_| Response::json(200, r#"{"works": "#));
+    let err = client(&server).fetch(annis::works()).unwrap_err();
+    assert!(matches!(err.kind(), ErrorKind::Decode));
+    assert!(std::error::Error::source(&err).is_some());
+}
+
+#[cfg(feature = "blocking")]
+#[test]
+fn network() {
+    let client = Client::builder().base_url("http://127.0.0.1:1").build();
+    let err = client.fetch(annis::works()).unwrap_err();
+    assert!(matches!(err.kind(), ErrorKind::Network));
+}
+
+#[cfg(feature = "blocking")]
+#[test]
+fn compose() {
+    fn run(server: &MockServer) -> Result<(), Box<dyn std::error::Error + Send + Sync>> {
+        client(server).fetch(annis::works())?;
+        Ok(())
+    }
+    let server = MockServer::start(|_| Response::empty(500));
+    let err = run(&server).unwrap_err();
+    assert!(err.downcast_ref::<Error>().is_some());
+}
+
+#[cfg(feature = "async")]
+#[tokio::test]
+async fn nonblocking() {
+    let server = MockServer::start(|_| Response::json(401, UNAUTHORIZED));
+    let client = annis::nonblocking::Client::builder()
+        .base_url(server.url())
+        .build();
+    let err = client.fetch(annis::works()).await.unwrap_err();
+    assert_eq!(err.api_errors()[0].short_message, "Invalid token");
+}
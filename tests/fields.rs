@@ -0,0 +1,163 @@
+#![cfg(feature = "blocking")]
+
+extern crate annis;
+mod common;
+
+use annis::models::PartialUser;
+use annis::{
+    ActivityField, CastField, CharacterField, Client, EpisodeField, ErrorKind, PersonField,
+    RecordField, SeriesField, StaffField, UserField, WorkField, Works,
+};
+use common::{MockServer, Response};
+
+#[test]
+fn field_paths() {
+    assert_eq!(WorkField::Title.to_string(), "title");
+    assert_eq!(
+        EpisodeField::Work(WorkField::Images).to_string(),
+        "work.images"
+    );
+    assert_eq!(
+        RecordField::Episode(EpisodeField::Work(WorkField::Title)).to_string(),
+        "episode.work.title"
+    );
+    assert_eq!(String::from(UserField::AvatarUrl), "avatar_url");
+    assert_eq!(
+        CastField::Character(CharacterField::Series(SeriesField::Name)).to_string(),
+        "character.series.name"
+    );
+    assert_eq!(
+        ActivityField::Record(RecordField::User(UserField::Username)).to_string(),
+        "record.user.username"
+    );
+    assert_eq!(
+        StaffField::Person(PersonField::Prefecture).to_string(),
+        "person.prefecture"
+    );
+}
+
+#[test]
+fn partial_works() {
+    let server = MockServer::start(|_| {
+        Response::json(
+            200,
+            r#"{"works": [{"title": "serial experiments lain"}], "total_count": 1, "next_page": null, "prev_page": null}"#,
+        )
+    });
+    let client = Client::builder().base_url(server.url()).build();
+    let works = annis::works()
+        .params(vec![(Works::filter_title, "lain"), (Works::fields, "id")])
+        .fields(vec![WorkField::Title, WorkField::Images])
+        .params(vec![(Works::per_page, "1")]);
+    let res = client.fetch(works).unwrap();
+    assert_eq!(
+        res.works[0].title.as_deref(),
+        Some("serial experiments lain")
+    );
+    assert_eq!(res.works[0].id, None);
+
+    let request = &server.requests()[0];
+    assert_eq!(request.param("fields").as_deref(), Some("title,images"));
+    assert_eq!(request.param("filter_title").as_deref(), Some("lain"));
+    assert_eq!(request.param("per_page").as_deref(), Some("1"));
+}
+
+#[test]
+fn params_after_fields() {
+    let server = MockServer::start(|_| {
+        Response::json(
+            200,
+            r#"{"works": [{"title": "serial experiments lain"}], "total_count": 1, "next_page": null, "prev_page": null}"#,
+        )
+    });
+    let client = Client::builder().base_url(server.url()).build();
+    let works = annis::works()
+        .fields(vec![WorkField::Title])
+        .try_params(vec![("filter_title", "lain")])
+        .unwrap()
+        .params(vec![("per_page", "1")]);
+    let res = client.fetch(works).unwrap();
+    assert_eq!(
+        res.works[0].title.as_deref(),
+        Some("serial experiments lain")
+    );
+
+    let request = &server.requests()[0];
+    assert_eq!(request.param("fields").as_deref(), Some("title"));
+    assert_eq!(request.param("filter_title").as_deref(), Some("lain"));
+    assert_eq!(request.param("per_page").as_deref(), Some("1"));
+
+    let err = annis::works()
+        .fields(vec![WorkField::Title])
+        .try_params(vec![("filter_unknown", "lain")])
+        .unwrap_err();
+    assert!(matches!(err.kind(), ErrorKind::InvalidParameter(_)));
+}
+
+#[test]
+fn partial_pages() {
+    let server = MockServer::start(|req| {
+        let body = match req.param("page").as_deref() {
+            Some("2") => {
+                r#"{"records": [{"comment": "Layer:02"}], "total_count": 2, "next_page": null, "prev_page": 1}"#
+            }
+            _ => {
+                r#"{"records": [{"episode": {"work": {"title": "lain"}}}], "total_count": 2, "next_page": 2, "prev_page": null}"#
+            }
+        };
+        Response::json(200, body)
+    });
+    let client = Client::builder().base_url(server.url()).build();
+    let records = annis::records().fields(vec![
+        RecordField::Comment,
+        RecordField::Episode(EpisodeField::Work(WorkField::Title)),
+    ]);
+    let records = client
+        .paginate(records)
+        .collect::<Result<Vec<_>, _>>()
+        .unwrap();
+    assert_eq!(records.len(), 2);
+    let work = records[0].episode.as_ref().and_then(|e| e.work.as_ref());
+    assert_eq!(work.and_then(|w| w.title.as_deref()), Some("lain"));
+    assert_eq!(records[1].comment.as_deref(), Some("Layer:02"));
+    assert_eq!(
+        server.requests()[1].param("fields").as_deref(),
+        Some("comment,episode.work.title")
+    );
+}
+
+#[test]
+fn partial_me() {
+    let server = MockServer::start(|_| Response::json(200, r#"{"username": "lain"}"#));
+    let client = Client::builder().base_url(server.url()).build();
+    let me: PartialUser = client
+        .fetch(annis::me().fields(vec![UserField::Username]))
+        .unwrap();
+    assert_eq!(me.username.as_deref(), Some("lain"));
+    assert_eq!(me.id, None);
+}
+
+#[test]
+fn partial_casts() {
+    let server = MockServer::start(|_| {
+        Response::json(
+            200,
+            r#"{"casts": [{"name": "Iwakura Lain", "character": {"series": {"name": "lain"}}}], "total_count": 1, "next_page": null, "prev_page": null}"#,
+        )
+    });
+    let client = Client::builder().base_url(server.url()).build();
+    let casts = annis::casts().fields(vec![
+        CastField::Name,
+        CastField::Character(CharacterField::Series(SeriesField::Name)),
+    ]);
+    let res = client.fetch(casts).unwrap();
+    let cast = &res.casts[0];
+    assert_eq!(cast.name.as_deref(), Some("Iwakura Lain"));
+    let series = cast.character.as_ref().and_then(|c| c.series.as_ref());
+    assert_eq!(series.and_then(|s| s.name.as_deref()), Some("lain"));
+    assert_eq!(cast.person, None);
+    assert_eq!(
+        server.requests()[0].param("fields").as_deref(),
+        Some("name,character.series.name")
+    );
+}
@@ -0,0 +1,142 @@
+#![cfg(feature = "blocking")]
+
+extern crate annis;
+mod common;
+
+use annis::{ErrorKind, OAuth};
+use common::{MockServer, Response};
+use std::net::TcpStream;
+use std::thread;
+use std::time::{Duration, Instant};
+
+const TOKEN: &str = r#"{
+    "access_token": "issued_token",
+    "token_type": "bearer",
+    "scope": "read write",
+    "created_at": 1460000000
+}"#;
+
+// Stands in for the browser following Annict's redirect.
+fn redirect(url: String) -> thread::JoinHandle<u16> {
+    thread::spawn(move || reqwest::blocking::get(url.as_str()).unwrap().status().as_u16())
+}
+
+#[test]
+fn login() {
+    let server = MockServer::start(|_| Response::json(200, TOKEN));
+    let auth = OAuth::client_id("client_id").base_url(server.url());
+    let mut login = auth.loopback(0).unwrap();
+    login.client_secret("client_secret_key").scope("read+write");
+
+    let url = reqwest::Url::parse(&login.authorize_url()).unwrap();
+    let param = |key: &str| {
+        url.query_pairs()
+            .find(|(k, _)| k == key)
+            .map(|(_, v)| v.into_owned())
+    };
+    assert_eq!(param("state").as_deref(), Some(login.state()));
+    assert_eq!(param("redirect_uri").as_deref(), Some(login.redirect_uri()));
+    assert_eq!(param("scope").as_deref(), Some("read write"));
+    assert!(login.redirect_uri().starts_with("http://127.0.0.1:"));
+    assert!(login.redirect_uri().ends_with("/callback"));
+
+    let favicon = login.redirect_uri().replace("/callback", "/favicon.ico");
+    let callback = format!(
+        "{}?code=issued_code&state={}",
+        login.redirect_uri(),
+        login.state()
+    );
+    let browser = thread::spawn(move || {
+        let favicon = redirect(favicon).join().unwrap();
+        (favicon, redirect(callback).join().unwrap())
+    });
+    let token = login.wait().unwrap();
+    assert_eq!(token.access_token, "issued_token");
+    assert_eq!(browser.join().unwrap(), (404, 200));
+
+    let request = &server.requests()[0];
+    assert!(request.body.contains("code=issued_code"));
+    assert!(request.body.contains("client_secret=client_secret_key"));
+}
+
+#[test]
+fn state_mismatch() {
+    let login = OAuth::client_id("client_id").loopback(0).unwrap();
+    let forged = redirect(format!(
+        "{}?code=forged_code&state=forged",
+        login.redirect_uri()
+    ));
+    let error = redirect(format!("{}?error=access_denied", login.redirect_uri()));
+    let callback = format!(
+        "{}?code=issued_code&state={}",
+        login.redirect_uri(),
+        login.state()
+    );
+    let browser = thread::spawn(move || {
+        let forged = forged.join().unwrap();
+        let error = error.join().unwrap();
+        (forged, error, redirect(callback).join().unwrap())
+    });
+
+    // -> Requests which aren't the redirect of this login don't end it.
+    assert_eq!(login.wait_for_code().unwrap(), "issued_code");
+    assert_eq!(browser.join().unwrap(), (400, 400, 200));
+}
+
+#[test]
+fn silent_connection() {
+    let login = OAuth::client_id("client_id").loopback(0).unwrap();
+    let addr = login
+        .redirect_uri()
+        .replace("http://", "")
+        .replace("/callback", "");
+    let _preconnect = TcpStream::connect(addr).unwrap();
+    let browser = redirect(format!(
+        "{}?code=issued_code&state={}",
+        login.redirect_uri(),
+        login.state()
+    ));
+    assert_eq!(login.wait_for_code().unwrap(), "issued_code");
+    assert_eq!(browser.join().unwrap(), 200);
+}
+
+#[test]
+fn timeout() {
+    let mut login = OAuth::client_id("client_id").loopback(0).unwrap();
+    login.timeout(Duration::from_millis(200));
+    let start = Instant::now();
+    let err = login.wait_for_code().unwrap_err();
+    assert!(matches!(err.kind(), ErrorKind::Timeout));
+    assert!(start.elapsed() < Duration::from_secs(2));
+}
+
+#[test]
+fn invalid_authorize_base_url() {
+    let err = OAuth::client_id("client_id")
+        .authorize_base_url("not a url")
+        .loopback(0)
+        .unwrap_err();
+    assert!(matches!(err.kind(), ErrorKind::InvalidParameter(_)));
+}
+
+#[test]
+fn access_denied() {
+    let login = OAuth::client_id("client_id").loopback(0).unwrap();
+    let browser = redirect(format!(
+        "{}?error=access_denied&state={}",
+        login.redirect_uri(),
+        login.state()
+    ));
+    let err = login.wait_for_code().unwrap_err();
+    assert!(matches!(err.kind(), ErrorKind::OAuth(e) if e.error == "access_denied"));
+    browser.join().unwrap();
+}
+
+#[test]
+fn random_state() {
+    let auth = OAuth::client_id("client_id");
+    let a = auth.loopback(0).unwrap();
+    let b = auth.loopback(0).unwrap();
+    assert_eq!(a.state().len(), 32);
+    assert_ne!(a.state(), b.state());
+}
@@ -0,0 +1,95 @@
+extern crate annis;
+use annis::models::{EpisodesResponse, StaffsResponse, User, WorksResponse};
+
+#[test]
+fn works_response() {
+    let json = r#"{
+        "works": [{
+            "id": 860,
+            "title": "serial experiments lain",
+            "title_kana": "しりあるえくすぺりめんつれいん",
+            "media": "tv",
+            "media_text": "TV",
+            "season_name": "1998-summer",
+            "season_name_text": "1998年夏",
+            "released_on": "",
+            "official_site_url": "",
+            "images": {
+                "recommended_url": "https://example.com/lain.png",
+                "facebook": { "og_image_url": "" },
+                "twitter": { "image_url": "" }
+            },
+            "episodes_count": 13,
+            "watchers_count": 4320,
+            "no_episodes": false
+        }],
+        "total_count": 1,
+        "next_page": null,
+        "prev_page": null
+    }"#;
+    let res: WorksResponse = serde_json::from_str(json).unwrap();
+    assert_eq!(res.total_count, 1);
+    assert_eq!(res.next_page, None);
+    assert_eq!(res.works[0].title, "serial experiments lain");
+    assert_eq!(res.works[0].episodes_count, Some(13));
+}
+
+#[test]
+fn episodes_response() {
+    let json = r#"{
+        "episodes": [{
+            "id": 45,
+            "number": "1",
+            "number_text": "第1話",
+            "sort_number": 10,
+            "title": "ゆゆ式",
+            "records_count": 200,
+            "work": { "id": 2274, "title": "ゆゆ式" },
+            "prev_episode": null,
+            "next_episode": { "id": 46, "number": "2", "title": null }
+        }],
+        "total_count": 12,
+        "next_page": 2,
+        "prev_page": null
+    }"#;
+    let res: EpisodesResponse = serde_json::from_str(json).unwrap();
+    assert_eq!(res.next_page, Some(2));
+    let episode = &res.episodes[0];
+    assert_eq!(episode.work.as_ref().unwrap().id, 2274);
+    assert_eq!(episode.next_episode.as_ref().unwrap().id, 46);
+    assert!(episode.prev_episode.is_none());
+}
+
+#[test]
+fn staffs_response() {
+    let json = r#"{
+        "staffs": [{
+            "id": 1,
+            "name": "京都アニメーション",
+            "role_text": "アニメーション制作",
+            "sort_number": 0,
+            "organization": { "id": 3, "name": "京都アニメーション" }
+        }],
+        "total_count": 1,
+        "next_page": null,
+        "prev_page": null
+    }"#;
+    let res: StaffsResponse = serde_json::from_str(json).unwrap();
+    assert!(res.staffs[0].person.is_none());
+    assert_eq!(res.staffs[0].organization.as_ref().unwrap().id, 3);
+}
+
+#[test]
+fn me_response() {
+    let json = r#"{
+        "id": 2,
+        "username": "shimbaco",
+        "name": "Koji Shimba",
+        "records_count": 2369,
+        "email": "me@example.com",
+        "notifications_count": 0
+    }"#;
+    let me: User = serde_json::from_str(json).unwrap();
+    assert_eq!(me.username, "shimbaco");
+    assert_eq!(me.email.as_deref(), Some("me@example.com"));
+}
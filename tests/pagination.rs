@@ -0,0 +1,189 @@
+#![cfg(any(feature = "blocking", feature = "async"))]
+
+extern crate annis;
+mod common;
+
+#[cfg(feature = "blocking")]
+use annis::Client;
+use annis::Works;
+use common::{MockServer, Request, Response};
+
+const PER_PAGE: u64 = 10;
+const LAST_PAGE: u64 = 3;
+
+/// Serves `LAST_PAGE` pages of works, chained by `next_page`.
+fn works_page(req: &Request) -> Response {
+    let page: u64 = req.param("page").and_then(|p| p.parse().ok()).unwrap_or(1);
+    let works = (0..PER_PAGE)
+        .map(|i| {
+            let id = (page - 1) * PER_PAGE + i + 1;
+            format!(r#"{{"id": {}, "title": "work {}"}}"#, id, id)
+        })
+        .collect::<Vec<_>>()
+        .join(",");
+    let next_page = if page < LAST_PAGE {
+        (page + 1).to_string()
+    } else {
+        "null".to_string()
+    };
+    let prev_page = if page > 1 {
+        (page - 1).to_string()
+    } else {
+        "null".to_string()
+    };
+    Response::json(
+        200,
+        &format!(
+            r#"{{"works": [{}], "total_count": {}, "next_page": {}, "prev_page": {}}}"#,
+            works,
+            PER_PAGE * LAST_PAGE,
+            next_page,
+            prev_page
+        ),
+    )
+}
+
+/// Serves two pages, where the second points back to `next_page`.
+fn looping_server(next_page: u64) -> MockServer {
+    MockServer::start(move |req| {
+        let next = match req.param("page").as_deref() {
+            Some("2") => next_page,
+            _ => 2,
+        };
+        Response::json(
+            200,
+            &format!(
+                r#"{{"works": [{{"id": 1, "title": "lain"}}], "total_count": 2, "next_page": {}, "prev_page": null}}"#,
+                next
+            ),
+        )
+    })
+}
+
+fn pages(server: &MockServer) -> Vec<Option<String>> {
+    server
+        .requests()
+        .iter()
+        .map(|req| req.param("page"))
+        .collect()
+}
+
+fn works() -> annis::Service<Works> {
+    annis::works().params(vec![
+        (Works::filter_season, "2016-spring"),
+        (Works::per_page, "10"),
+    ])
+}
+
+#[cfg(feature = "blocking")]
+#[test]
+fn paginate() {
+    let server = MockServer::start(works_page);
+    let client = Client::builder().base_url(server.url()).build();
+    let works = client
+        .paginate(works())
+        .collect::<Result<Vec<_>, _>>()
+        .unwrap();
+    assert_eq!(works.len(), 30);
+    assert_eq!(works[0].id, 1);
+    assert_eq!(works[29].id, 30);
+    assert_eq!(
+        pages(&server),
+        vec![Some("1".into()), Some("2".into()), Some("3".into())]
+    );
+
+    let request = &server.requests()[2];
+    assert_eq!(
+        request.param("filter_season").as_deref(),
+        Some("2016-spring")
+    );
+    assert_eq!(request.param("per_page").as_deref(), Some("10"));
+}
+
+#[cfg(feature = "blocking")]
+#[test]
+fn max_items() {
+    let server = MockServer::start(works_page);
+    let client = Client::builder().base_url(server.url()).build();
+    let works = client
+        .paginate(works())
+        .max_items(15)
+        .collect::<Result<Vec<_>, _>>()
+        .unwrap();
+    assert_eq!(works.len(), 15);
+    assert_eq!(works[14].id, 15);
+    assert_eq!(pages(&server), vec![Some("1".into()), Some("2".into())]);
+}
+
+#[cfg(feature = "blocking")]
+#[test]
+fn max_pages() {
+    let server = MockServer::start(works_page);
+    let client = Client::builder().base_url(server.url()).build();
+    let works = client
+        .paginate(works())
+        .max_pages(2)
+        .collect::<Result<Vec<_>, _>>()
+        .unwrap();
+    assert_eq!(works.len(), 20);
+    assert_eq!(pages(&server), vec![Some("1".into()), Some("2".into())]);
+}
+
+#[cfg(feature = "blocking")]
+#[test]
+fn next_page_does_not_go_forward() {
+    use annis::Api;
+
+    for next_page in [2, 1] {
+        let server = looping_server(next_page);
+        let client = Client::builder().base_url(server.url()).build();
+        let all = client
+            .paginate(works())
+            .collect::<Result<Vec<_>, _>>()
+            .unwrap();
+        assert_eq!(all.len(), 2);
+        assert_eq!(pages(&server), vec![Some("1".into()), Some("2".into())]);
+
+        assert_eq!(client.fetch_all(works()).unwrap().len(), 2);
+        assert_eq!(server.requests().len(), 4);
+    }
+}
+
+#[cfg(feature = "async")]
+#[tokio::test]
+async fn paginate_nonblocking() {
+    use futures::TryStreamExt;
+
+    let server = MockServer::start(works_page);
+    let client = annis::nonblocking::Client::builder()
+        .base_url(server.url())
+        .build();
+    let all: Vec<_> = client.paginate(works()).try_collect().await.unwrap();
+    assert_eq!(all.len(), 30);
+    assert_eq!(
+        pages(&server),
+        vec![Some("1".into()), Some("2".into()), Some("3".into())]
+    );
+
+    let some: Vec<_> = client
+        .paginate(works())
+        .max_items(15)
+        .try_collect()
+        .await
+        .unwrap();
+    assert_eq!(some.len(), 15);
+}
+
+#[cfg(feature = "async")]
+#[tokio::test]
+async fn next_page_does_not_go_forward_nonblocking() {
+    use futures::TryStreamExt;
+
+    let server = looping_server(2);
+    let client = annis::nonblocking::Client::builder()
+        .base_url(server.url())
+        .build();
+    let all: Vec<_> = client.paginate(works()).try_collect().await.unwrap();
+    assert_eq!(all.len(), 2);
+    assert_eq!(pages(&server), vec![Some("1".into()), Some("2".into())]);
+}
@@ -0,0 +1,56 @@
+#![cfg(feature = "blocking")]
+
+extern crate annis;
+mod common;
+
+use annis::{Client, ErrorKind, MeStatuses, Works};
+use common::{MockServer, Response};
+
+const WORKS: &str = r#"{"works": [], "total_count": 0, "next_page": null, "prev_page": null}"#;
+
+#[test]
+fn from_str() {
+    assert_eq!("filter_title".parse::<Works>().unwrap(), Works::filter_title);
+    assert_eq!("kind".parse::<MeStatuses>().unwrap(), MeStatuses::kind);
+    assert_eq!(Works::from("fields"), Works::fields);
+    assert_eq!(Works::from(String::from("page")), Works::page);
+
+    match "filter_titel".parse::<Works>().unwrap_err().kind() {
+        ErrorKind::InvalidParameter(message) => assert!(message.contains("filter_titel")),
+        kind => panic!("unexpected {:?}", kind),
+    }
+    assert!("Invalid".parse::<Works>().is_err());
+    assert_eq!(Works::from("filter_titel"), Works::Invalid);
+}
+
+#[test]
+fn string_keys_are_sent() {
+    let server = MockServer::start(|_| Response::json(200, WORKS));
+    let client = Client::builder().base_url(server.url()).build();
+    client
+        .fetch(annis::works().params(vec![("filter_title", "lain"), ("fields", "title")]))
+        .unwrap();
+
+    let request = &server.requests()[0];
+    assert_eq!(request.param("filter_title").as_deref(), Some("lain"));
+    assert_eq!(request.param("fields").as_deref(), Some("title"));
+}
+
+#[test]
+fn try_params() {
+    let service = annis::works()
+        .try_params(vec![("filter_title", "lain"), ("sort_id", "desc")])
+        .unwrap();
+    assert_eq!(
+        service.params,
+        Some(vec![
+            (Works::filter_title, "lain".to_string()),
+            (Works::sort_id, "desc".to_string()),
+        ])
+    );
+
+    let err = annis::works()
+        .try_params(vec![("filter_title", "lain"), ("filter_titel", "lain")])
+        .unwrap_err();
+    assert!(matches!(err.kind(), ErrorKind::InvalidParameter(_)));
+}
@@ -0,0 +1,92 @@
+#![cfg(any(feature = "blocking", feature = "async"))]
+
+extern crate annis;
+mod common;
+
+#[cfg(feature = "blocking")]
+use annis::Client;
+use annis::{ErrorKind, Works};
+use common::{MockServer, Response};
+use std::thread;
+use std::time::Duration;
+
+const WORKS: &str = r#"{"works": [], "total_count": 0, "next_page": null, "prev_page": null}"#;
+
+fn slow_server() -> MockServer {
+    MockServer::start(|req| {
+        if req.param("filter_title").as_deref() == Some("slow") {
+            thread::sleep(Duration::from_millis(500));
+        }
+        Response::json(200, WORKS)
+    })
+}
+
+#[cfg(feature = "blocking")]
+#[test]
+fn timeout() {
+    let server = slow_server();
+    let client = Client::builder()
+        .base_url(server.url())
+        .timeout(Duration::from_millis(100))
+        .connect_timeout(Duration::from_secs(1))
+        .pool_idle_timeout(Duration::from_secs(30))
+        .pool_max_idle_per_host(2)
+        .build();
+
+    let err = client
+        .fetch(annis::works().params(vec![(Works::filter_title, "slow")]))
+        .unwrap_err();
+    assert!(matches!(err.kind(), ErrorKind::Timeout));
+}
+
+#[cfg(feature = "blocking")]
+#[test]
+fn clones() {
+    let server = slow_server();
+    let client = Client::builder().base_url(server.url()).build();
+
+    let handles: Vec<_> = (0..3)
+        .map(|_| {
+            let client = client.clone();
+            thread::spawn(move || client.fetch(annis::works()).map(|res| res.total_count))
+        })
+        .collect();
+    for handle in handles {
+        assert_eq!(handle.join().unwrap().unwrap(), 0);
+    }
+    assert_eq!(server.requests().len(), 3);
+}
+
+#[cfg(feature = "blocking")]
+#[test]
+fn clones_share_connections() {
+    let server = MockServer::keep_alive(|_| Response::json(200, WORKS));
+    let client = Client::builder().base_url(server.url()).build();
+    let clone = client.clone();
+
+    client.fetch(annis::works()).unwrap();
+    clone.fetch(annis::works()).unwrap();
+    assert_eq!(server.requests().len(), 2);
+    assert_eq!(server.connections(), 1);
+
+    let other = Client::builder().base_url(server.url()).build();
+    other.fetch(annis::works()).unwrap();
+    assert_eq!(server.connections(), 2);
+}
+
+#[cfg(feature = "async")]
+#[tokio::test]
+async fn nonblocking_timeout() {
+    let server = slow_server();
+    let client = annis::nonblocking::Client::builder()
+        .base_url(server.url())
+        .timeout(Duration::from_millis(100))
+        .try_build()
+        .unwrap();
+
+    let err = client
+        .fetch(annis::works().params(vec![(Works::filter_title, "slow")]))
+        .await
+        .unwrap_err();
+    assert!(matches!(err.kind(), ErrorKind::Timeout));
+}
@@ -0,0 +1,120 @@
+#![cfg(any(feature = "blocking", feature = "async"))]
+
+extern crate annis;
+mod common;
+
+#[cfg(feature = "blocking")]
+use annis::Client;
+use annis::{RateLimiter};
+use common::{MockServer, Response};
+use std::time::{Duration, Instant};
+
+const WORKS: &str = r#"{"works": [], "total_count": 0, "next_page": null, "prev_page": null}"#;
+
+#[cfg(feature = "blocking")]
+#[test]
+fn token_bucket() {
+    let server = MockServer::start(|_| Response::json(200, WORKS));
+    let limiter = RateLimiter::new(2, Duration::from_millis(800));
+    let client = Client::builder()
+        .base_url(server.url())
+        .rate_limit(limiter.clone())
+        .build();
+    assert_eq!(limiter.budget().available, 2);
+
+    let start = Instant::now();
+    client.fetch(annis::works()).unwrap();
+    client.clone().fetch(annis::works()).unwrap();
+    assert_eq!(limiter.budget().available, 0);
+    assert!(start.elapsed() < Duration::from_millis(300));
+
+    // -> The third request waits for the bucket to refill.
+    client.fetch(annis::works()).unwrap();
+    assert!(start.elapsed() >= Duration::from_millis(350));
+    assert_eq!(client.rate_limiter().unwrap().budget().capacity, 2);
+}
+
+#[cfg(feature = "blocking")]
+#[test]
+fn headers() {
+    let server = MockServer::start(|_| {
+        Response::json(200, WORKS)
+            .header("X-RateLimit-Limit", "60")
+            .header("X-RateLimit-Remaining", "0")
+            .header("X-RateLimit-Reset", "1")
+    });
+    let limiter = RateLimiter::new(100, Duration::from_secs(1));
+    let client = Client::builder()
+        .base_url(server.url())
+        .rate_limit(limiter.clone())
+        .build();
+
+    client.fetch(annis::works()).unwrap();
+    let budget = limiter.budget();
+    assert_eq!(budget.limit, Some(60));
+    assert_eq!(budget.remaining, Some(0));
+    assert!(budget.paused_for.unwrap() > Duration::from_millis(500));
+
+    let start = Instant::now();
+    client.fetch(annis::works()).unwrap();
+    assert!(start.elapsed() >= Duration::from_millis(500));
+}
+
+#[cfg(feature = "blocking")]
+#[test]
+fn retry_after() {
+    let server = MockServer::start(|_| Response::json(429, "{}").header("Retry-After", "1"));
+    let limiter = RateLimiter::new(100, Duration::from_secs(1));
+    let client = Client::builder()
+        .base_url(server.url())
+        .rate_limit(limiter.clone())
+        .build();
+
+    assert!(client.fetch(annis::works()).is_err());
+    assert!(limiter.budget().paused_for.is_some());
+}
+
+#[cfg(feature = "blocking")]
+#[test]
+fn huge_retry_after() {
+    let server = MockServer::start(|_| {
+        Response::json(200, WORKS)
+            .header("Retry-After", "18446744073709551615")
+            .header("X-RateLimit-Remaining", "0")
+            .header("X-RateLimit-Reset", "18446744073709551615")
+    });
+    let limiter = RateLimiter::new(100, Duration::from_secs(1));
+    let client = Client::builder()
+        .base_url(server.url())
+        .rate_limit(limiter.clone())
+        .build();
+
+    client.call(annis::works()).unwrap();
+    let paused_for = limiter.budget().paused_for.unwrap();
+    assert!(paused_for > Duration::from_secs(59 * 60));
+    assert!(paused_for <= Duration::from_secs(60 * 60));
+}
+
+#[cfg(feature = "async")]
+#[tokio::test]
+async fn nonblocking() {
+    let server = MockServer::start(|_| Response::json(200, WORKS));
+    let limiter = RateLimiter::new(1, Duration::from_millis(500));
+    let client = annis::nonblocking::Client::builder()
+        .base_url(server.url())
+        .rate_limit(limiter)
+        .build();
+
+    let start = Instant::now();
+    client.fetch(annis::works()).await.unwrap();
+
+    // -> The executor keeps running other tasks while the second request waits.
+    let ticker = async {
+        tokio::time::sleep(Duration::from_millis(50)).await;
+        start.elapsed()
+    };
+    let (res, ticked) = tokio::join!(client.fetch(annis::works()), ticker);
+    res.unwrap();
+    assert!(ticked < Duration::from_millis(300));
+    assert!(start.elapsed() >= Duration::from_millis(400));
+}
@@ -1,3 +1,5 @@
+#![cfg(feature = "blocking")]
+
 extern crate annis;
 use annis::{AccessToken, AuthorizeUrl, Client, OAuth, Works::*};
 use std::env;
@@ -17,7 +19,8 @@ fn main() {
         .access_token()
         .client_secret("client_secret_key")
         .code("certification code")
-        .build();
+        .build()
+        .map(|token| token.access_token);
 
     let client = Client::set_token(env::var("annict_access_token").unwrap());
     let works = annis::works().params(vec![(filter_title, "lain")]);
@@ -38,6 +41,8 @@ fn auth_requests() {
         client_id: env::var("annict_client_id").unwrap(),
         redirect_uri: "urn:ietf:wg:oauth:2.0:oob".to_string(),
         scope: "read".to_string(),
+        state: None,
+        base_url: "https://annict.com".to_string(),
     }
     .build();
 
@@ -48,15 +53,18 @@ fn auth_requests() {
         .access_token()
         .client_secret("client_secret_key")
         .code("certification code")
-        .build();
+        .build()
+        .map_err(|e| e.to_string());
 
     let manual = AccessToken {
         client_id: env::var("annict_client_id").unwrap(),
         client_secret: "client_secret_key".to_string(),
         code: "certification code".to_string(),
         redirect_uri: "urn:ietf:wg:oauth:2.0:oob".into(),
+        base_url: "https://api.annict.com".into(),
     }
-    .build();
+    .build()
+    .map_err(|e| e.to_string());
 
     assert_eq!(instant, manual);
 }
@@ -0,0 +1,174 @@
+#![cfg(any(feature = "blocking", feature = "async"))]
+
+extern crate annis;
+mod common;
+
+#[cfg(feature = "blocking")]
+use annis::Client;
+use annis::RetryPolicy;
+#[cfg(feature = "blocking")]
+use annis::{ErrorKind, MeRecords, Method};
+use common::{MockServer, Response};
+#[cfg(feature = "blocking")]
+use reqwest::StatusCode;
+use std::sync::atomic::{AtomicUsize, Ordering};
+use std::time::Duration;
+
+const WORKS: &str = r#"{"works": [], "total_count": 0, "next_page": null, "prev_page": null}"#;
+
+/// A server which fails the first `failures` requests with `status`.
+fn flaky_server(failures: usize, status: u16) -> MockServer {
+    let count = AtomicUsize::new(0);
+    MockServer::start(move |_| {
+        if count.fetch_add(1, Ordering::SeqCst) < failures {
+            Response::json(status, r#"{"errors": []}"#).header("Retry-After", "0")
+        } else {
+            Response::json(200, WORKS)
+        }
+    })
+}
+
+fn policy() -> RetryPolicy {
+    RetryPolicy::new().base_delay(Duration::from_millis(1))
+}
+
+#[cfg(feature = "blocking")]
+#[test]
+fn retries_server_errors() {
+    let server = flaky_server(2, 503);
+    let client = Client::builder()
+        .base_url(server.url())
+        .retry(policy())
+        .build();
+
+    client.fetch(annis::works()).unwrap();
+    assert_eq!(server.requests().len(), 3);
+}
+
+#[cfg(feature = "blocking")]
+#[test]
+fn gives_up() {
+    let server = flaky_server(5, 500);
+    let client = Client::builder()
+        .base_url(server.url())
+        .retry(policy().max_attempts(2))
+        .build();
+
+    let err = client.fetch(annis::works()).unwrap_err();
+    assert_eq!(err.status(), Some(StatusCode::INTERNAL_SERVER_ERROR));
+    assert_eq!(server.requests().len(), 2);
+}
+
+#[cfg(feature = "blocking")]
+#[test]
+fn not_retryable() {
+    let server = flaky_server(1, 404);
+    let client = Client::builder()
+        .base_url(server.url())
+        .retry(policy())
+        .build();
+    assert!(client.fetch(annis::works()).is_err());
+    assert_eq!(server.requests().len(), 1);
+
+    let server = flaky_server(1, 503);
+    let client = Client::builder().base_url(server.url()).build();
+    assert!(client.fetch(annis::works()).is_err());
+    assert_eq!(server.requests().len(), 1);
+}
+
+#[cfg(feature = "blocking")]
+#[test]
+fn rate_limited() {
+    let server = flaky_server(1, 429);
+    let client = Client::builder()
+        .base_url(server.url())
+        .retry(policy().base_delay(Duration::from_secs(60)))
+        .build();
+
+    client.fetch(annis::works()).unwrap();
+    assert_eq!(server.requests().len(), 2);
+}
+
+#[cfg(feature = "blocking")]
+#[test]
+fn long_retry_after() {
+    let server = MockServer::start(|_| {
+        Response::json(429, r#"{"errors": []}"#).header("Retry-After", "18446744073709551615")
+    });
+    let client = Client::builder()
+        .base_url(server.url())
+        .retry(policy().max_delay(Duration::from_secs(10)))
+        .build();
+
+    let err = client.fetch(annis::works()).unwrap_err();
+    assert!(matches!(
+        err.kind(),
+        ErrorKind::RateLimited {
+            retry_after: Some(_),
+            ..
+        }
+    ));
+    assert_eq!(server.requests().len(), 1);
+}
+
+#[cfg(feature = "blocking")]
+#[test]
+fn post_is_not_resent() {
+    let record = || {
+        annis::me_records(Method::POST, 5013).params(vec![(MeRecords::comment, "good")])
+    };
+
+    let server = flaky_server(1, 503);
+    let client = Client::builder()
+        .base_url(server.url())
+        .retry(policy())
+        .build();
+    assert!(client.call(record()).is_err());
+    assert_eq!(server.requests().len(), 1);
+
+    let server = flaky_server(1, 503);
+    let client = Client::builder()
+        .base_url(server.url())
+        .retry(policy().retry_non_idempotent(true))
+        .build();
+    client.call(record()).unwrap();
+    let requests = server.requests();
+    assert_eq!(requests.len(), 2);
+    assert_eq!(requests[1].form_param("comment").as_deref(), Some("good"));
+}
+
+#[cfg(feature = "blocking")]
+#[test]
+fn network() {
+    let client = Client::builder()
+        .base_url("http://127.0.0.1:1")
+        .retry(policy().max_attempts(2))
+        .build();
+    let err = client.fetch(annis::works()).unwrap_err();
+    assert!(matches!(err.kind(), ErrorKind::Network));
+}
+
+#[cfg(feature = "blocking")]
+#[test]
+fn backoff() {
+    let policy = RetryPolicy::new()
+        .base_delay(Duration::from_millis(100))
+        .max_delay(Duration::from_millis(300));
+    assert_eq!(policy.backoff(1), Duration::from_millis(100));
+    assert_eq!(policy.backoff(2), Duration::from_millis(200));
+    assert_eq!(policy.backoff(3), Duration::from_millis(300));
+    assert_eq!(policy.backoff(100), Duration::from_millis(300));
+}
+
+#[cfg(feature = "async")]
+#[tokio::test]
+async fn nonblocking() {
+    let server = flaky_server(2, 502);
+    let client = annis::nonblocking::Client::builder()
+        .base_url(server.url())
+        .retry(policy())
+        .build();
+
+    client.fetch(annis::works()).await.unwrap();
+    assert_eq!(server.requests().len(), 3);
+}
@@ -0,0 +1,91 @@
+#![cfg(feature = "blocking")]
+
+extern crate annis;
+mod common;
+
+use annis::models::RecordsResponse;
+use annis::{Client, ErrorKind, MeRecords, Method, RatingState, ReviewDraft};
+use common::{MockServer, Response};
+
+#[test]
+fn rating_state() {
+    assert_eq!("great".parse::<RatingState>().unwrap(), RatingState::Great);
+    assert_eq!(RatingState::Average.to_string(), "average");
+    assert!("excellent".parse::<RatingState>().is_err());
+    assert!(RatingState::Bad < RatingState::Great);
+}
+
+#[test]
+fn create() {
+    let server = MockServer::start(|_| Response::json(200, r#"{"id": 1}"#));
+    let client = Client::builder().base_url(server.url()).build();
+    let review = ReviewDraft::new("Present day, present time.")
+        .title("Lain")
+        .animation(RatingState::Good)
+        .overall(RatingState::Great)
+        .share_twitter(true);
+    let posted = client.fetch(review.create(860).unwrap()).unwrap();
+    assert_eq!(posted.map(|r| r.id), Some(1));
+
+    let request = &server.requests()[0];
+    assert_eq!(request.method, "POST");
+    assert_eq!(request.path, "/v1/me/reviews");
+    assert_eq!(request.form_param("work_id").as_deref(), Some("860"));
+    assert_eq!(
+        request.form_param("body").as_deref(),
+        Some("Present day, present time.")
+    );
+    assert_eq!(request.form_param("title").as_deref(), Some("Lain"));
+    assert_eq!(
+        request.form_param("rating_animation_state").as_deref(),
+        Some("good")
+    );
+    assert_eq!(
+        request.form_param("rating_overall_state").as_deref(),
+        Some("great")
+    );
+    assert_eq!(request.form_param("rating_music_state"), None);
+    assert_eq!(request.form_param("share_twitter").as_deref(), Some("true"));
+    assert_eq!(request.form_param("share_facebook"), None);
+}
+
+#[test]
+fn update() {
+    let review = ReviewDraft {
+        body: "edited".to_string(),
+        story: Some(RatingState::Bad),
+        ..ReviewDraft::default()
+    };
+    let service = review.update(10).unwrap();
+    assert_eq!(service.method, reqwest::Method::PATCH);
+    assert_eq!(service.path, "/v1/me/reviews/10");
+
+    let err = ReviewDraft::new(" ").create(860).unwrap_err();
+    assert!(matches!(err.kind(), ErrorKind::InvalidParameter(_)));
+}
+
+#[test]
+fn record_rating_state() {
+    let server = MockServer::start(|_| Response::json(200, r#"{"id": 1}"#));
+    let client = Client::builder().base_url(server.url()).build();
+    client
+        .call(
+            annis::me_records(Method::POST, 5013)
+                .params(vec![(MeRecords::rating_state, RatingState::Good)]),
+        )
+        .unwrap();
+    assert_eq!(
+        server.requests()[0].form_param("rating_state").as_deref(),
+        Some("good")
+    );
+
+    let json = r#"{
+        "records": [{ "id": 1, "rating": 4.5, "rating_state": "great" }],
+        "total_count": 1,
+        "next_page": null,
+        "prev_page": null
+    }"#;
+    let res: RecordsResponse = serde_json::from_str(json).unwrap();
+    assert_eq!(res.records[0].rating_state, Some(RatingState::Great));
+    assert_eq!(res.records[0].rating, Some(4.5));
+}
@@ -0,0 +1,126 @@
+#![cfg(feature = "blocking")]
+
+extern crate annis;
+mod common;
+
+use annis::{Client, ErrorKind, MeWorks, Order, Quarter, Season, Works};
+use common::{MockServer, Response};
+
+const WORKS: &str = r#"{"works": [], "total_count": 0, "next_page": null, "prev_page": null}"#;
+
+#[test]
+fn parse_and_format() {
+    let season: Season = "2024-spring".parse().unwrap();
+    assert_eq!(season, Season::new(2024, Quarter::Spring));
+    assert_eq!(season.to_string(), "2024-spring");
+    assert_eq!("2016-all".parse::<Season>().unwrap(), Season::all(2016));
+    assert_eq!(Season::all(2016).to_string(), "2016-all");
+    assert_eq!(
+        "2016-fall".parse::<Season>().unwrap().to_string(),
+        "2016-autumn"
+    );
+
+    for invalid in &["2024", "2024-spirng", "spring-2024", "-spring", ""] {
+        match invalid.parse::<Season>().unwrap_err().kind() {
+            ErrorKind::InvalidParameter(_) => {}
+            kind => panic!("unexpected {:?}", kind),
+        }
+    }
+}
+
+#[test]
+fn next_and_prev() {
+    let autumn = Season::new(2023, Quarter::Autumn);
+    assert_eq!(autumn.next(), Some(Season::new(2024, Quarter::Winter)));
+    assert_eq!(autumn.next().and_then(|s| s.prev()), Some(autumn));
+    assert_eq!(
+        Season::new(2024, Quarter::Spring).prev(),
+        Some(Season::new(2024, Quarter::Winter))
+    );
+    assert_eq!(Season::all(2024).next(), Some(Season::all(2025)));
+    assert_eq!(Season::all(2024).prev(), Some(Season::all(2023)));
+}
+
+#[test]
+fn next_and_prev_out_of_years() {
+    assert_eq!(Season::new(0, Quarter::Winter).prev(), None);
+    assert_eq!(Season::all(0).prev(), None);
+    assert_eq!(
+        Season::new(0, Quarter::Spring).prev(),
+        Some(Season::new(0, Quarter::Winter))
+    );
+    assert_eq!(Season::new(u32::MAX, Quarter::Autumn).next(), None);
+    assert_eq!(Season::all(u32::MAX).next(), None);
+
+    let last = Season::new(u32::MAX, Quarter::Summer)..=Season::all(u32::MAX);
+    assert_eq!(Season::range(last).count(), 2);
+}
+
+#[test]
+fn range() {
+    let seasons: Vec<String> =
+        Season::range(Season::new(2020, Quarter::Summer)..=Season::new(2021, Quarter::Spring))
+            .map(String::from)
+            .collect();
+    assert_eq!(
+        seasons,
+        ["2020-summer", "2020-autumn", "2021-winter", "2021-spring"]
+    );
+
+    assert_eq!(
+        Season::range(Season::all(2020)..=Season::all(2021)).count(),
+        8
+    );
+    let empty = Season::new(2021, Quarter::Winter)..=Season::new(2020, Quarter::Winter);
+    assert_eq!(Season::range(empty).count(), 0);
+}
+
+#[test]
+fn current() {
+    let season = Season::current();
+    assert!(season.year >= 2024);
+    assert!(season.quarter.is_some());
+    assert_eq!(Quarter::of_month(1), Quarter::Winter);
+    assert_eq!(Quarter::of_month(6), Quarter::Spring);
+    assert_eq!(Quarter::of_month(7), Quarter::Summer);
+    assert_eq!(Quarter::of_month(12), Quarter::Autumn);
+}
+
+#[test]
+fn serde() {
+    let season = Season::new(2024, Quarter::Winter);
+    assert_eq!(serde_json::to_string(&season).unwrap(), r#""2024-winter""#);
+    assert_eq!(
+        serde_json::from_str::<Season>(r#""2024-all""#).unwrap(),
+        Season::all(2024)
+    );
+}
+
+#[test]
+fn as_param() {
+    let server = MockServer::start(|_| Response::json(200, WORKS));
+    let client = Client::builder().base_url(server.url()).build();
+    client
+        .fetch(annis::works().params(vec![(
+            Works::filter_season,
+            Season::new(2024, Quarter::Spring),
+        )]))
+        .unwrap();
+    client
+        .fetch(annis::me_works().params(vec![
+            (MeWorks::filter_season, String::from(Season::all(2024))),
+            (MeWorks::sort_season, Order::Desc.into()),
+        ]))
+        .unwrap();
+
+    let requests = server.requests();
+    assert_eq!(
+        requests[0].param("filter_season").as_deref(),
+        Some("2024-spring")
+    );
+    assert_eq!(
+        requests[1].param("filter_season").as_deref(),
+        Some("2024-all")
+    );
+    assert_eq!(requests[1].param("sort_season").as_deref(), Some("desc"));
+}
@@ -0,0 +1,79 @@
+#![cfg(feature = "blocking")]
+
+extern crate annis;
+mod common;
+
+use annis::models::ActivitiesResponse;
+use annis::{Client, MeWorks, WatchStatus};
+use common::{MockServer, Response};
+
+#[test]
+fn parse_and_format() {
+    assert_eq!(
+        "wanna_watch".parse::<WatchStatus>().unwrap(),
+        WatchStatus::WannaWatch
+    );
+    assert_eq!(
+        "stop_watching".parse::<WatchStatus>().unwrap(),
+        WatchStatus::StopWatching
+    );
+    assert_eq!(WatchStatus::OnHold.to_string(), "on_hold");
+    assert_eq!(String::from(WatchStatus::NoSelect), "no_select");
+    assert!("wached".parse::<WatchStatus>().is_err());
+    assert_eq!(
+        serde_json::to_string(&WatchStatus::Watched).unwrap(),
+        r#""watched""#
+    );
+}
+
+#[test]
+fn me_statuses_set() {
+    let server = MockServer::start(|_| Response::empty(204));
+    let client = Client::builder().base_url(server.url()).build();
+    client
+        .fetch(annis::me_statuses_set(3994, WatchStatus::WannaWatch))
+        .unwrap();
+
+    let request = &server.requests()[0];
+    assert_eq!(request.method, "POST");
+    assert_eq!(request.path, "/v1/me/statuses");
+    assert_eq!(request.form_param("work_id").as_deref(), Some("3994"));
+    assert_eq!(request.form_param("kind").as_deref(), Some("wanna_watch"));
+}
+
+#[test]
+fn filter_status() {
+    let server = MockServer::start(|_| {
+        Response::json(
+            200,
+            r#"{"works": [], "total_count": 0, "next_page": null, "prev_page": null}"#,
+        )
+    });
+    let client = Client::builder().base_url(server.url()).build();
+    client
+        .fetch(annis::me_works().params(vec![(MeWorks::filter_status, WatchStatus::Watching)]))
+        .unwrap();
+    assert_eq!(
+        server.requests()[0].param("filter_status").as_deref(),
+        Some("watching")
+    );
+}
+
+#[test]
+fn status_kind() {
+    let json = r#"{
+        "activities": [{
+            "id": 1,
+            "action": "create_status",
+            "status": { "kind": "on_hold" }
+        }],
+        "total_count": 1,
+        "next_page": null,
+        "prev_page": null
+    }"#;
+    let res: ActivitiesResponse = serde_json::from_str(json).unwrap();
+    assert_eq!(
+        res.activities[0].status.as_ref().unwrap().kind,
+        WatchStatus::OnHold
+    );
+}
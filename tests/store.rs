@@ -0,0 +1,151 @@
+extern crate annis;
+mod common;
+
+use annis::models::TokenResponse;
+#[cfg(feature = "blocking")]
+use annis::Client;
+#[cfg(feature = "blocking")]
+use annis::{ErrorKind, OAuth};
+use annis::{FileTokenStore, MemoryTokenStore, Scope, TokenStore};
+#[cfg(any(feature = "blocking", feature = "async"))]
+use common::{MockServer, Response};
+use std::env;
+use std::fs;
+
+const TOKEN: &str = r#"{
+    "access_token": "issued_token",
+    "token_type": "bearer",
+    "scope": "read write",
+    "created_at": 1460000000
+}"#;
+
+fn token() -> TokenResponse {
+    serde_json::from_str(TOKEN).unwrap()
+}
+
+fn temp_path(name: &str) -> std::path::PathBuf {
+    let dir = env::temp_dir().join(format!("annis-test-{}-{}", std::process::id(), name));
+    let _ = fs::remove_dir_all(&dir);
+    dir.join("token.json")
+}
+
+#[test]
+fn memory_store() {
+    let store = MemoryTokenStore::new();
+    assert_eq!(store.load().unwrap(), None);
+    store.save(&token()).unwrap();
+    assert_eq!(store.load().unwrap(), Some(token()));
+    store.clear().unwrap();
+    assert_eq!(store.load().unwrap(), None);
+}
+
+#[test]
+fn file_store() {
+    let path = temp_path("file");
+    let store = FileTokenStore::new(&path);
+    assert_eq!(store.load().unwrap(), None);
+
+    store.save(&token()).unwrap();
+    let reloaded = FileTokenStore::new(&path).load().unwrap().unwrap();
+    assert_eq!(reloaded, token());
+    assert!(reloaded.has_scope(Scope::Write));
+
+    #[cfg(unix)]
+    {
+        use std::os::unix::fs::PermissionsExt;
+        let mode = fs::metadata(&path).unwrap().permissions().mode();
+        assert_eq!(mode & 0o777, 0o600);
+    }
+
+    store.clear().unwrap();
+    assert!(!path.exists());
+    store.clear().unwrap();
+}
+
+#[test]
+fn concurrent_saves() {
+    let path = temp_path("concurrent");
+    let planted = path.with_file_name("token.json.tmp");
+    fs::create_dir_all(path.parent().unwrap()).unwrap();
+    fs::write(&planted, "planted").unwrap();
+
+    let handles: Vec<_> = (0..8)
+        .map(|_| {
+            let store = FileTokenStore::new(&path);
+            std::thread::spawn(move || store.save(&token()))
+        })
+        .collect();
+    for handle in handles {
+        handle.join().unwrap().unwrap();
+    }
+    assert_eq!(FileTokenStore::new(&path).load().unwrap(), Some(token()));
+    assert_eq!(fs::read_to_string(&planted).unwrap(), "planted");
+
+    let files = fs::read_dir(path.parent().unwrap()).unwrap().count();
+    assert_eq!(files, 2);
+}
+
+#[cfg(feature = "blocking")]
+#[test]
+fn missing_token() {
+    let err = Client::from_store(MemoryTokenStore::new()).unwrap_err();
+    assert!(matches!(err.kind(), ErrorKind::MissingToken));
+}
+
+#[cfg(feature = "blocking")]
+#[test]
+fn session() {
+    let server = MockServer::start(|req| match req.path.as_str() {
+        "/oauth/token" => Response::json(200, TOKEN),
+        "/oauth/revoke" => Response::json(200, "{}"),
+        _ => Response::json(
+            200,
+            r#"{"works": [], "total_count": 0, "next_page": null, "prev_page": null}"#,
+        ),
+    });
+    let path = temp_path("session");
+
+    OAuth::client_id("client_id")
+        .base_url(server.url())
+        .access_token()
+        .code("certification code")
+        .build_and_save(&FileTokenStore::new(&path))
+        .unwrap();
+
+    // -> The process restarts.
+    let client = Client::builder()
+        .base_url(server.url())
+        .build_with_store(FileTokenStore::new(&path))
+        .unwrap();
+    assert_eq!(client.token, "issued_token");
+    client.fetch(annis::works()).unwrap();
+
+    client.revoke().unwrap();
+    assert!(!path.exists());
+    let revoke = &server.requests()[2];
+    assert_eq!(revoke.path, "/oauth/revoke");
+    assert_eq!(revoke.form_param("token").as_deref(), Some("issued_token"));
+}
+
+#[cfg(feature = "async")]
+#[tokio::test]
+async fn nonblocking_session() {
+    let server = MockServer::start(|req| match req.path.as_str() {
+        "/oauth/token" => Response::json(200, TOKEN),
+        _ => Response::json(200, "{}"),
+    });
+    let store = MemoryTokenStore::new();
+    annis::nonblocking::OAuth::client_id("client_id")
+        .base_url(server.url())
+        .access_token()
+        .build_and_save_async(&store)
+        .await
+        .unwrap();
+    assert!(store.load().unwrap().is_some());
+
+    let client = annis::nonblocking::Client::builder()
+        .base_url(server.url())
+        .build_with_store(store)
+        .unwrap();
+    client.revoke().await.unwrap();
+}
@@ -0,0 +1,189 @@
+#![cfg(any(feature = "blocking", feature = "async"))]
+
+extern crate annis;
+
+#[cfg(feature = "blocking")]
+use annis::models::WorksResponse;
+#[cfg(feature = "async")]
+use annis::AsyncTransport;
+#[cfg(feature = "blocking")]
+use annis::Client;
+use annis::{Api, Error, HttpRequest, HttpResponse, Transport, Works};
+#[cfg(feature = "blocking")]
+use annis::{OAuth, RetryPolicy};
+#[cfg(feature = "async")]
+use futures::future::BoxFuture;
+use reqwest::header::HeaderMap;
+use reqwest::StatusCode;
+use std::sync::{Arc, Mutex};
+#[cfg(feature = "blocking")]
+use std::time::Duration;
+
+/// Returns recorded responses in order, and keeps the requests.
+#[derive(Debug, Clone, Default)]
+struct Recorded {
+    responses: Arc<Mutex<Vec<(u16, String)>>>,
+    requests: Arc<Mutex<Vec<HttpRequest>>>,
+}
+
+impl Recorded {
+    fn new(responses: &[(u16, &str)]) -> Recorded {
+        let responses = responses
+            .iter()
+            .rev()
+            .map(|(status, body)| (*status, body.to_string()))
+            .collect();
+        Recorded {
+            responses: Arc::new(Mutex::new(responses)),
+            ..Recorded::default()
+        }
+    }
+
+    fn requests(&self) -> Vec<HttpRequest> {
+        self.requests.lock().unwrap().clone()
+    }
+
+    fn respond(&self, request: HttpRequest) -> Result<HttpResponse, Error> {
+        self.requests.lock().unwrap().push(request);
+        let (status, body) = self
+            .responses
+            .lock()
+            .unwrap()
+            .pop()
+            .expect("no response left");
+        Ok(HttpResponse {
+            status: StatusCode::from_u16(status).unwrap(),
+            headers: HeaderMap::new(),
+            body: body.into_bytes(),
+        })
+    }
+}
+
+impl Transport for Recorded {
+    fn send(&self, request: HttpRequest) -> Result<HttpResponse, Error> {
+        self.respond(request)
+    }
+}
+
+#[cfg(feature = "async")]
+impl AsyncTransport for Recorded {
+    fn send(&self, request: HttpRequest) -> BoxFuture<'_, Result<HttpResponse, Error>> {
+        Box::pin(async move { self.respond(request) })
+    }
+}
+
+const PAGE_1: &str = r#"{"works": [{"id": 1, "title": "serial experiments lain"}], "total_count": 2, "next_page": 2, "prev_page": null}"#;
+const PAGE_2: &str = r#"{"works": [{"id": 2, "title": "Lain"}], "total_count": 2, "next_page": null, "prev_page": 1}"#;
+
+fn titles<A: Api>(api: &A) -> A::Output<Vec<String>> {
+    let works = api.fetch_all(annis::works().params(vec![(Works::filter_title, "lain")]));
+    A::map(works, |works| works.into_iter().map(|w| w.title).collect())
+}
+
+#[cfg(feature = "blocking")]
+#[test]
+fn blocking_transport() {
+    let recorded = Recorded::new(&[(200, PAGE_1)]);
+    let client = Client::builder()
+        .token("secret")
+        .transport(recorded.clone())
+        .build();
+    let res: WorksResponse = client
+        .fetch(annis::works().params(vec![(Works::filter_title, "lain")]))
+        .unwrap();
+    assert_eq!(res.works[0].title, "serial experiments lain");
+
+    let request = &recorded.requests()[0];
+    assert_eq!(request.method, reqwest::Method::GET);
+    assert_eq!(request.url.path(), "/v1/works");
+    assert_eq!(request.url.query(), Some("filter_title=lain"));
+    assert_eq!(request.headers["authorization"], "Bearer secret");
+}
+
+#[cfg(feature = "blocking")]
+#[test]
+fn transport_is_retried() {
+    let recorded = Recorded::new(&[(503, "{}"), (200, PAGE_2)]);
+    let client = Client::builder()
+        .transport(recorded.clone())
+        .retry(
+            RetryPolicy::new()
+                .base_delay(Duration::from_millis(1))
+                .jitter(false),
+        )
+        .build();
+    assert_eq!(client.fetch(annis::works()).unwrap().works[0].id, 2);
+    assert_eq!(recorded.requests().len(), 2);
+}
+
+#[cfg(feature = "blocking")]
+#[test]
+fn api_with_blocking_client() {
+    let recorded = Recorded::new(&[(200, PAGE_1), (200, PAGE_2)]);
+    let client = Client::builder().transport(recorded.clone()).build();
+    assert_eq!(
+        titles(&client).unwrap(),
+        ["serial experiments lain", "Lain"]
+    );
+    assert_eq!(
+        recorded.requests()[1].url.query(),
+        Some("filter_title=lain&page=2")
+    );
+}
+
+#[cfg(feature = "async")]
+#[tokio::test]
+async fn api_with_nonblocking_client() {
+    let recorded = Recorded::new(&[(200, PAGE_1), (200, PAGE_2)]);
+    let client = annis::nonblocking::Client::builder()
+        .transport(recorded.clone())
+        .build();
+    assert_eq!(
+        titles(&client).await.unwrap(),
+        ["serial experiments lain", "Lain"]
+    );
+    assert_eq!(recorded.requests().len(), 2);
+}
+
+#[cfg(feature = "blocking")]
+#[test]
+fn access_token_with_transport() {
+    let recorded = Recorded::new(&[(
+        200,
+        r#"{"access_token": "token", "token_type": "bearer", "scope": "read", "created_at": 0}"#,
+    )]);
+    let token = OAuth::client_id("client")
+        .access_token()
+        .client_secret("secret")
+        .code("code")
+        .build_with(&recorded)
+        .unwrap();
+    assert_eq!(token.access_token, "token");
+
+    let request = &recorded.requests()[0];
+    assert_eq!(request.method, reqwest::Method::POST);
+    assert_eq!(request.url.as_str(), "https://api.annict.com/oauth/token");
+    let body = String::from_utf8(request.body.clone().unwrap()).unwrap();
+    assert!(body.contains("grant_type=authorization_code"));
+    assert!(body.contains("code=code"));
+}
+
+#[cfg(feature = "async")]
+#[tokio::test]
+async fn access_token_with_async_transport() {
+    let recorded = Recorded::new(&[(
+        200,
+        r#"{"access_token": "token", "token_type": "bearer", "scope": "read", "created_at": 0}"#,
+    )]);
+    let token = annis::nonblocking::OAuth::client_id("client")
+        .access_token()
+        .code("code")
+        .build_with_async(&recorded)
+        .await
+        .unwrap();
+    assert_eq!(token.access_token, "token");
+    assert_eq!(
+        recorded.requests()[0].url.as_str(),
+        "https://api.annict.com/oauth/token"
+    );
+}